@@ -0,0 +1,322 @@
+//! Persistent character sheets
+//!
+//! Sheets live in a JSON file keyed by guild and Discord user, so each player
+//! has one character per server. Names of stats, skills and attacks are
+//! matched case-insensitively.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use serenity::all::{GuildId, UserId};
+use serenity::prelude::{RwLock, TypeMapKey};
+
+use crate::dice::defense::DefenseModifiers;
+use crate::dice::save::{save_bonus, SaveType};
+
+/// Default location of the character store
+pub const DEFAULT_STORE_PATH: &str = "characters.json";
+
+/// A player's character sheet
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Character {
+    pub name: String,
+    pub level: i32,
+    /// Stat bonuses, e.g. "Dexterity" → 2
+    pub stats: BTreeMap<String, i32>,
+    /// Total skill bonuses, e.g. "Stealth" → 12
+    pub skills: BTreeMap<String, i32>,
+    /// Total Attack Bonuses by weapon, e.g. "Longsword" → 15
+    pub attacks: BTreeMap<String, i32>,
+    /// Kin Modifier for each save, keyed by "TSR", "RSR" or "WSR"
+    pub kin: BTreeMap<String, i32>,
+    /// Armor Dexterity penalty, subtracted from DM
+    pub armor_penalty: i32,
+    /// Armor Quality DM bonus
+    pub armor_quality: i32,
+    /// Shield DM bonus
+    pub shield: i32,
+    /// Magic item DM bonuses
+    pub magic_dm: i32,
+    /// Other DM bonuses not covered above
+    ///
+    /// Older sheets stored their total DM as `dm`; that total would count
+    /// Dexterity and armor twice, so it's ignored when loading.
+    pub dm_other: i32,
+    /// Weapon Damage Rating
+    pub dr: i32,
+    /// Maximum Hits (0 if not recorded)
+    pub hits: i32,
+}
+
+/// Find an entry by name, ignoring case
+fn find_entry<'a>(entries: &'a BTreeMap<String, i32>, name: &str) -> Option<(&'a str, i32)> {
+    entries
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(key, value)| (key.as_str(), *value))
+}
+
+/// Set an entry, keeping the existing spelling of its name if there is one
+fn set_entry(entries: &mut BTreeMap<String, i32>, name: &str, value: i32) {
+    let key = find_entry(entries, name)
+        .map(|(key, _)| key.to_string())
+        .unwrap_or_else(|| name.to_string());
+    entries.insert(key, value);
+}
+
+impl Character {
+    /// Create an empty sheet
+    pub fn new(name: &str, level: i32) -> Self {
+        Character {
+            name: name.to_string(),
+            level,
+            ..Default::default()
+        }
+    }
+
+    /// Stat bonus by name (0 if not recorded)
+    pub fn stat(&self, name: &str) -> i32 {
+        find_entry(&self.stats, name).map_or(0, |(_, value)| value)
+    }
+
+    pub fn set_stat(&mut self, name: &str, value: i32) {
+        set_entry(&mut self.stats, name, value);
+    }
+
+    /// Skill bonus by name, with the name as written on the sheet
+    pub fn skill(&self, name: &str) -> Option<(&str, i32)> {
+        find_entry(&self.skills, name)
+    }
+
+    pub fn set_skill(&mut self, name: &str, value: i32) {
+        set_entry(&mut self.skills, name, value);
+    }
+
+    /// Attack Bonus by weapon name, with the name as written on the sheet
+    pub fn attack(&self, name: &str) -> Option<(&str, i32)> {
+        find_entry(&self.attacks, name)
+    }
+
+    pub fn set_attack(&mut self, name: &str, value: i32) {
+        set_entry(&mut self.attacks, name, value);
+    }
+
+    /// Kin Modifier for a save (0 if not recorded)
+    pub fn kin(&self, save: SaveType) -> i32 {
+        find_entry(&self.kin, save.choice()).map_or(0, |(_, value)| value)
+    }
+
+    pub fn set_kin(&mut self, save: SaveType, value: i32) {
+        set_entry(&mut self.kin, save.choice(), value);
+    }
+
+    pub fn dexterity(&self) -> i32 {
+        self.stat("Dexterity")
+    }
+
+    /// Defensive Modifier components from the sheet, before cover and Parrying
+    pub fn defense(&self) -> DefenseModifiers {
+        DefenseModifiers {
+            dexterity: self.dexterity(),
+            armor_penalty: self.armor_penalty,
+            armor_quality: self.armor_quality,
+            shield: self.shield,
+            magic: self.magic_dm,
+            other: self.dm_other,
+            ..Default::default()
+        }
+    }
+
+    /// Save Roll bonus: 7 + Stat + Kin Modifier + Level
+    pub fn save_bonus(&self, save: SaveType) -> i32 {
+        save_bonus(self.stat(save.stat()), self.kin(save), self.level)
+    }
+
+    /// Format the sheet for Discord display
+    pub fn format_sheet(&self) -> String {
+        let list = |entries: &BTreeMap<String, i32>| {
+            if entries.is_empty() {
+                "—".to_string()
+            } else {
+                entries
+                    .iter()
+                    .map(|(name, value)| format!("{} {:+}", name, value))
+                    .collect::<Vec<_>>()
+                    .join(" | ")
+            }
+        };
+        let saves = SaveType::ALL
+            .iter()
+            .map(|save| format!("{} {:+}", save.choice(), self.save_bonus(*save)))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        let defense = self.defense();
+
+        format!(
+            "📜 **{}** (Level {})\n━━━━━━━━━━━━━━\n**Stats:** {}\n**Skills:** {}\n**Attacks:** {}\n**Saves:** {}\nHits: {} | DR: {}\n**DM:** {:+} ({})",
+            self.name,
+            self.level,
+            list(&self.stats),
+            list(&self.skills),
+            list(&self.attacks),
+            saves,
+            self.hits,
+            self.dr,
+            defense.total(),
+            defense.breakdown()
+        )
+    }
+}
+
+/// All character sheets, backed by a JSON file
+#[derive(Debug)]
+pub struct CharacterStore {
+    path: PathBuf,
+    characters: BTreeMap<String, Character>,
+}
+
+/// Store key: "guild:user" (guild 0 for direct messages)
+fn store_key(guild_id: Option<GuildId>, user_id: UserId) -> String {
+    format!("{}:{}", guild_id.map_or(0, |g| g.get()), user_id.get())
+}
+
+impl CharacterStore {
+    /// Load the store from `path`, starting empty if the file doesn't exist yet
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let characters = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents)?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(CharacterStore { path, characters })
+    }
+
+    /// Write the store back to its file
+    pub fn save(&self) -> io::Result<()> {
+        let contents = serde_json::to_string_pretty(&self.characters)?;
+        let tmp_path = self.path.with_extension("json.tmp");
+        fs::write(&tmp_path, contents)?;
+        fs::rename(&tmp_path, &self.path)
+    }
+
+    pub fn get(&self, guild_id: Option<GuildId>, user_id: UserId) -> Option<&Character> {
+        self.characters.get(&store_key(guild_id, user_id))
+    }
+
+    /// Store a character sheet and write the store back to its file
+    ///
+    /// If the write fails the previous sheet is put back, so an unsaved change
+    /// never lingers in memory to be written by a later save.
+    pub fn save_character(
+        &mut self,
+        guild_id: Option<GuildId>,
+        user_id: UserId,
+        character: Character,
+    ) -> io::Result<()> {
+        let key = store_key(guild_id, user_id);
+        let previous = self.characters.insert(key.clone(), character);
+        let result = self.save();
+        if result.is_err() {
+            match previous {
+                Some(previous) => self.characters.insert(key, previous),
+                None => self.characters.remove(&key),
+            };
+        }
+        result
+    }
+}
+
+/// Shared character store in the client's data map
+pub struct CharacterStoreKey;
+
+impl TypeMapKey for CharacterStoreKey {
+    type Value = Arc<RwLock<CharacterStore>>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_save_bonus_from_sheet() {
+        let mut character = Character::new("Aria", 3);
+        character.set_stat("Dexterity", 2);
+        character.set_kin(SaveType::Reflex, 1);
+        assert_eq!(character.save_bonus(SaveType::Reflex), 7 + 2 + 1 + 3);
+        assert_eq!(character.save_bonus(SaveType::Will), 7 + 3);
+    }
+
+    #[test]
+    fn test_names_ignore_case() {
+        let mut character = Character::new("Aria", 1);
+        character.set_skill("Stealth", 10);
+        character.set_skill("stealth", 12);
+        assert_eq!(character.skills.len(), 1);
+        assert_eq!(character.skill("STEALTH"), Some(("Stealth", 12)));
+        assert_eq!(character.skill("Climb"), None);
+        character.set_stat("dexterity", 3);
+        assert_eq!(character.dexterity(), 3);
+    }
+
+    #[test]
+    fn test_defense_from_sheet() {
+        let mut character = Character::new("Aria", 1);
+        character.set_stat("Dexterity", 3);
+        character.armor_penalty = 2;
+        character.shield = 4;
+        assert_eq!(character.defense().total(), 3 - 2 + 4);
+    }
+
+    #[test]
+    fn test_old_total_dm_ignored() {
+        let character: Character =
+            serde_json::from_str(r#"{"name": "Aria", "stats": {"Dexterity": 3}, "dm": 8}"#).unwrap();
+        assert_eq!(character.dm_other, 0);
+        assert_eq!(character.defense().total(), 3);
+    }
+
+    #[test]
+    fn test_store_round_trip() {
+        let path = std::env::temp_dir().join(format!("fe-characters-{}.json", std::process::id()));
+        let guild = Some(GuildId::new(1));
+        let user = UserId::new(2);
+
+        let mut store = CharacterStore::load(&path).unwrap();
+        assert!(store.get(guild, user).is_none());
+        let mut character = Character::new("Aria", 2);
+        character.set_attack("Longsword", 14);
+        store.save_character(guild, user, character.clone()).unwrap();
+
+        let reloaded = CharacterStore::load(&path).unwrap();
+        assert_eq!(reloaded.get(guild, user), Some(&character));
+        assert!(reloaded.get(None, user).is_none());
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_failed_save_keeps_previous_sheet() {
+        let path = std::env::temp_dir().join(format!("fe-characters-rollback-{}.json", std::process::id()));
+        let guild = Some(GuildId::new(1));
+        let user = UserId::new(2);
+
+        let mut store = CharacterStore::load(&path).unwrap();
+        let character = Character::new("Aria", 2);
+        store.save_character(guild, user, character.clone()).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // Point the store at a directory that doesn't exist so writes fail
+        store.path = std::env::temp_dir()
+            .join(format!("fe-missing-{}", std::process::id()))
+            .join("characters.json");
+        assert!(store.save_character(guild, user, Character::new("Bram", 5)).is_err());
+        assert_eq!(store.get(guild, user), Some(&character));
+        assert!(store.save_character(None, user, Character::new("Bram", 5)).is_err());
+        assert!(store.get(None, user).is_none());
+    }
+}
@@ -0,0 +1,393 @@
+//! Combat conditions and status effects
+//!
+//! Critical effects (Bleed, Dazed, Pain, Stun) and the combat conditions
+//! table, with durations ticked down during the Upkeep phase.
+
+use std::fmt;
+
+use crate::dice::feat::RollKind;
+
+/// A condition from the Combat Conditions table or a critical effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConditionKind {
+    /// Lose # Hits each Upkeep until healed/bandaged
+    Bleed,
+    /// -4 to all actions (not DM/Saves)
+    Bruised,
+    /// -4 to all actions for # rounds
+    Dazed,
+    /// Die in # rounds unless healed
+    Dying,
+    Engaged,
+    Flanking,
+    Frightened,
+    /// Cannot move, -6 AB and DM
+    Held,
+    Incapacitated,
+    OnRear,
+    /// -# to actions
+    Pain,
+    /// Half DM, -4 AB
+    Prone,
+    /// No Full Actions/Attacks/Spells for # rounds, Parry at 3:1
+    Stunned,
+    Surprised,
+    Weary,
+}
+
+impl ConditionKind {
+    /// All conditions, alphabetical
+    pub const ALL: [ConditionKind; 15] = [
+        ConditionKind::Bleed,
+        ConditionKind::Bruised,
+        ConditionKind::Dazed,
+        ConditionKind::Dying,
+        ConditionKind::Engaged,
+        ConditionKind::Flanking,
+        ConditionKind::Frightened,
+        ConditionKind::Held,
+        ConditionKind::Incapacitated,
+        ConditionKind::OnRear,
+        ConditionKind::Pain,
+        ConditionKind::Prone,
+        ConditionKind::Stunned,
+        ConditionKind::Surprised,
+        ConditionKind::Weary,
+    ];
+
+    /// Parse a slash command choice value
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|k| k.choice() == value)
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            ConditionKind::Bleed => "bleed",
+            ConditionKind::Bruised => "bruised",
+            ConditionKind::Dazed => "dazed",
+            ConditionKind::Dying => "dying",
+            ConditionKind::Engaged => "engaged",
+            ConditionKind::Flanking => "flanking",
+            ConditionKind::Frightened => "frightened",
+            ConditionKind::Held => "held",
+            ConditionKind::Incapacitated => "incapacitated",
+            ConditionKind::OnRear => "on_rear",
+            ConditionKind::Pain => "pain",
+            ConditionKind::Prone => "prone",
+            ConditionKind::Stunned => "stunned",
+            ConditionKind::Surprised => "surprised",
+            ConditionKind::Weary => "weary",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            ConditionKind::Bleed => "Bleed",
+            ConditionKind::Bruised => "Bruised",
+            ConditionKind::Dazed => "Dazed",
+            ConditionKind::Dying => "Dying",
+            ConditionKind::Engaged => "Engaged",
+            ConditionKind::Flanking => "Flanking",
+            ConditionKind::Frightened => "Frightened",
+            ConditionKind::Held => "Held",
+            ConditionKind::Incapacitated => "Incapacitated",
+            ConditionKind::OnRear => "On Rear",
+            ConditionKind::Pain => "Pain",
+            ConditionKind::Prone => "Prone",
+            ConditionKind::Stunned => "Stunned",
+            ConditionKind::Surprised => "Surprised",
+            ConditionKind::Weary => "Weary",
+        }
+    }
+
+    /// Get description of the effect
+    pub fn effect(&self) -> &'static str {
+        match self {
+            ConditionKind::Bleed => "Lose # Hits each Upkeep until healed/bandaged",
+            ConditionKind::Bruised => "-4 to all Actions (not DM/Saves)",
+            ConditionKind::Dazed => "-4 to all actions",
+            ConditionKind::Dying => "Dies when the rounds run out unless healed",
+            ConditionKind::Engaged => "Cannot move without Disengage/Run Away",
+            ConditionKind::Flanking => "Attacker gets +3 AB; Shield only protects Shield-side",
+            ConditionKind::Frightened => "Cannot attack source, may flee",
+            ConditionKind::Held => "Cannot move, -6 AB and DM, attacks get +6, only Hand/Short weapons",
+            ConditionKind::Incapacitated => "Cannot act/Parry, attacks auto-resolve as 35",
+            ConditionKind::OnRear => "Attacker gets +6 AB, no Shield bonus to DM",
+            ConditionKind::Pain => "-# to actions",
+            ConditionKind::Prone => "Half DM, -4 AB, no 2-handed weapons, +4 vs ranged",
+            ConditionKind::Stunned => "No Full Actions/Attacks/Spells, Parry at 3:1",
+            ConditionKind::Surprised => "Cannot Attack/Cast, Half/Free Actions only, no Parry/Shield",
+            ConditionKind::Weary => "Half base move, 1 Action/round, no healing",
+        }
+    }
+
+    /// Whether the condition's # is an amount (Bleed, Pain) rather than a duration
+    pub fn has_magnitude(&self) -> bool {
+        matches!(self, ConditionKind::Bleed | ConditionKind::Pain)
+    }
+
+    /// Whether the condition's # is a number of rounds, as in Stun(2)
+    pub fn counts_rounds(&self) -> bool {
+        matches!(
+            self,
+            ConditionKind::Dazed | ConditionKind::Dying | ConditionKind::Stunned
+        )
+    }
+}
+
+/// A condition on a combatant
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Condition {
+    pub kind: ConditionKind,
+    /// Amount for Bleed and Pain
+    pub value: u8,
+    /// Rounds left; None lasts until removed
+    pub rounds: Option<u8>,
+}
+
+impl Condition {
+    /// Build a condition from the # in the critical notation
+    ///
+    /// For Bleed/Pain `value` is the amount; for Dazed/Stunned/Dying it's the
+    /// number of rounds unless `rounds` is given.
+    pub fn new(kind: ConditionKind, value: u8, rounds: Option<u8>) -> Self {
+        if kind.has_magnitude() {
+            Condition {
+                kind,
+                value: value.max(1),
+                rounds,
+            }
+        } else if kind.counts_rounds() {
+            Condition {
+                kind,
+                value: 0,
+                rounds: rounds.or(Some(value.max(1))),
+            }
+        } else {
+            Condition {
+                kind,
+                value: 0,
+                rounds,
+            }
+        }
+    }
+
+    /// Penalty this condition gives to a roll
+    ///
+    /// Dazed and Pain hit all actions; Bruised spares Save Rolls; Held and
+    /// Prone only hit the Attack Bonus. Penalties to the DM are separate.
+    pub fn roll_penalty(&self, kind: RollKind) -> i32 {
+        match (self.kind, kind) {
+            (ConditionKind::Dazed, _) => -4,
+            (ConditionKind::Pain, _) => -(self.value as i32),
+            (ConditionKind::Bruised, RollKind::Save) => 0,
+            (ConditionKind::Bruised, _) => -4,
+            (ConditionKind::Held, RollKind::Attack) => -6,
+            (ConditionKind::Prone, RollKind::Attack) => -4,
+            _ => 0,
+        }
+    }
+
+    /// Why this condition forbids a roll, if it does
+    pub fn restriction(&self, kind: RollKind) -> Option<&'static str> {
+        match (self.kind, kind) {
+            (ConditionKind::Incapacitated, _) => Some("cannot act"),
+            (ConditionKind::Stunned | ConditionKind::Surprised, RollKind::Attack) => {
+                Some("cannot Attack")
+            }
+            (ConditionKind::Stunned | ConditionKind::Surprised, RollKind::Cast) => {
+                Some("cannot cast Spells")
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Condition penalties to one roll, itemized by source
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RollPenalties {
+    /// Each condition that changes the roll, with its penalty
+    pub items: Vec<(String, i32)>,
+    /// Conditions that forbid the roll, with the reason
+    pub restrictions: Vec<String>,
+}
+
+impl RollPenalties {
+    /// Total penalty to the roll
+    pub fn total(&self) -> i32 {
+        self.items.iter().map(|(_, penalty)| penalty).sum()
+    }
+
+    /// Format the penalties for Discord display, e.g. "Dazed -4 | Pain(2) -2"
+    pub fn breakdown(&self) -> String {
+        self.items
+            .iter()
+            .map(|(name, penalty)| format!("{} {:+}", name, penalty))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+}
+
+/// Itemize the penalties a set of conditions gives to a roll
+pub fn roll_penalties(conditions: &[Condition], kind: RollKind) -> RollPenalties {
+    let mut penalties = RollPenalties::default();
+    for condition in conditions {
+        let penalty = condition.roll_penalty(kind);
+        if penalty != 0 {
+            let name = if condition.kind.has_magnitude() {
+                format!("{}({})", condition.kind.name(), condition.value)
+            } else {
+                condition.kind.name().to_string()
+            };
+            penalties.items.push((name, penalty));
+        }
+        if let Some(reason) = condition.restriction(kind) {
+            penalties
+                .restrictions
+                .push(format!("{}: {}", condition.kind.name(), reason));
+        }
+    }
+    penalties
+}
+
+impl fmt::Display for Condition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.kind.name())?;
+        if self.kind.has_magnitude() {
+            write!(f, "({})", self.value)?;
+        }
+        if let Some(rounds) = self.rounds {
+            write!(f, " [{} rnd]", rounds)?;
+        }
+        Ok(())
+    }
+}
+
+/// Add a condition, stacking with one of the same kind
+///
+/// Amounts (Bleed, Pain) and round-counted effects (Dazed, Stunned, Dying) add
+/// up; other conditions keep the longer duration.
+pub fn add_condition(conditions: &mut Vec<Condition>, condition: Condition) {
+    let Some(existing) = conditions.iter_mut().find(|c| c.kind == condition.kind) else {
+        conditions.push(condition);
+        return;
+    };
+
+    if condition.kind.has_magnitude() {
+        existing.value = existing.value.saturating_add(condition.value);
+    }
+    existing.rounds = match (existing.rounds, condition.rounds) {
+        (Some(a), Some(b)) if condition.kind.counts_rounds() => Some(a.saturating_add(b)),
+        (Some(a), Some(b)) => Some(a.max(b)),
+        _ => None,
+    };
+}
+
+/// What happened to one combatant's conditions during Upkeep
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UpkeepResult {
+    /// Hits lost to bleeding
+    pub bleeding: i32,
+    /// Conditions that ran out this Upkeep
+    pub expired: Vec<ConditionKind>,
+}
+
+/// Apply bleeding, tick down timed conditions and drop the ones that ran out
+pub fn upkeep(conditions: &mut Vec<Condition>) -> UpkeepResult {
+    let bleeding = conditions
+        .iter()
+        .filter(|c| c.kind == ConditionKind::Bleed)
+        .map(|c| c.value as i32)
+        .sum();
+
+    let mut expired = Vec::new();
+    conditions.retain_mut(|condition| match condition.rounds {
+        Some(rounds) if rounds <= 1 => {
+            expired.push(condition.kind);
+            false
+        }
+        Some(rounds) => {
+            condition.rounds = Some(rounds - 1);
+            true
+        }
+        None => true,
+    });
+
+    UpkeepResult { bleeding, expired }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_critical_notation() {
+        let stun = Condition::new(ConditionKind::Stunned, 2, None);
+        assert_eq!(stun.rounds, Some(2));
+        let bleed = Condition::new(ConditionKind::Bleed, 3, None);
+        assert_eq!((bleed.value, bleed.rounds), (3, None));
+        assert_eq!(bleed.to_string(), "Bleed(3)");
+        let prone = Condition::new(ConditionKind::Prone, 0, None);
+        assert_eq!(prone.to_string(), "Prone");
+    }
+
+    #[test]
+    fn test_stacking() {
+        let mut conditions = Vec::new();
+        add_condition(&mut conditions, Condition::new(ConditionKind::Bleed, 1, None));
+        add_condition(&mut conditions, Condition::new(ConditionKind::Bleed, 2, None));
+        add_condition(&mut conditions, Condition::new(ConditionKind::Stunned, 1, None));
+        add_condition(&mut conditions, Condition::new(ConditionKind::Stunned, 2, None));
+        assert_eq!(conditions.len(), 2);
+        assert_eq!(conditions[0].value, 3);
+        assert_eq!(conditions[1].rounds, Some(3));
+    }
+
+    #[test]
+    fn test_upkeep() {
+        let mut conditions = vec![
+            Condition::new(ConditionKind::Bleed, 2, None),
+            Condition::new(ConditionKind::Bleed, 1, None),
+            Condition::new(ConditionKind::Stunned, 1, None),
+            Condition::new(ConditionKind::Dazed, 2, None),
+            Condition::new(ConditionKind::Prone, 0, None),
+        ];
+        let result = upkeep(&mut conditions);
+        assert_eq!(result.bleeding, 3);
+        assert_eq!(result.expired, vec![ConditionKind::Stunned]);
+        assert_eq!(conditions.len(), 4);
+
+        let result = upkeep(&mut conditions);
+        assert_eq!(result.expired, vec![ConditionKind::Dazed]);
+        assert!(conditions.iter().any(|c| c.kind == ConditionKind::Prone));
+    }
+
+    #[test]
+    fn test_penalty_scopes() {
+        let conditions = vec![
+            Condition::new(ConditionKind::Dazed, 1, None),
+            Condition::new(ConditionKind::Bruised, 0, None),
+            Condition::new(ConditionKind::Pain, 2, None),
+            Condition::new(ConditionKind::Held, 0, None),
+            Condition::new(ConditionKind::Prone, 0, None),
+        ];
+        assert_eq!(roll_penalties(&conditions, RollKind::Attack).total(), -4 - 4 - 2 - 6 - 4);
+        assert_eq!(roll_penalties(&conditions, RollKind::Skill).total(), -4 - 4 - 2);
+        assert_eq!(roll_penalties(&conditions, RollKind::Cast).total(), -4 - 4 - 2);
+
+        // Bruised spares Save Rolls; Dazed and Pain hit all actions
+        let save = roll_penalties(&conditions, RollKind::Save);
+        assert_eq!(save.total(), -4 - 2);
+        assert_eq!(save.breakdown(), "Dazed -4 | Pain(2) -2");
+    }
+
+    #[test]
+    fn test_restrictions() {
+        let conditions = vec![Condition::new(ConditionKind::Stunned, 1, None)];
+        assert_eq!(roll_penalties(&conditions, RollKind::Attack).restrictions.len(), 1);
+        assert_eq!(roll_penalties(&conditions, RollKind::Cast).restrictions.len(), 1);
+        assert!(roll_penalties(&conditions, RollKind::Save).restrictions.is_empty());
+    }
+}
@@ -0,0 +1,552 @@
+//! Combat encounter and initiative tracking
+//!
+//! Each tactical round runs through three phases:
+//! 1. Initiative & Upkeep
+//! 2. Declare Actions
+//! 3. Resolve Actions, one combatant at a time in Initiative order (highest first)
+
+use serenity::all::{MessageId, UserId};
+
+use super::conditions::{self, Condition, ConditionKind, RollPenalties, UpkeepResult};
+use super::wounds::WoundLevel;
+use crate::dice::defense::DefenseModifiers;
+use crate::dice::feat::RollKind;
+use crate::dice::roll_2d10_closed;
+use crate::dice::source::DiceSource;
+
+/// Phase of a tactical round
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Phase {
+    /// Roll/check Initiative, bleeding, Stun, ended spell effects
+    #[default]
+    Upkeep,
+    /// GM declares opponents' actions, then players; allocate Parrying
+    Declare,
+    /// Resolve actions in Initiative order
+    Resolve,
+}
+
+impl Phase {
+    /// Phase number in the round sequence
+    pub fn number(&self) -> u8 {
+        match self {
+            Phase::Upkeep => 1,
+            Phase::Declare => 2,
+            Phase::Resolve => 3,
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Phase::Upkeep => "Initiative & Upkeep",
+            Phase::Declare => "Declare Actions",
+            Phase::Resolve => "Resolve Actions",
+        }
+    }
+
+    /// Get description of what happens in the phase
+    pub fn description(&self) -> &'static str {
+        match self {
+            Phase::Upkeep => "Roll/check Initiative, apply bleeding, remove 1 round of Stun, end spell effects.",
+            Phase::Declare => "GM declares opponent actions first, then players. Allocate Parrying.",
+            Phase::Resolve => "Resolve actions in Initiative order, highest first.",
+        }
+    }
+}
+
+/// An Initiative roll: 2d10 (not open-ended) + bonus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InitiativeRoll {
+    pub dice: (u8, u8),
+    pub total: i32,
+}
+
+/// A PC or NPC taking part in an encounter
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Combatant {
+    pub name: String,
+    /// Discord user playing this combatant (None for NPCs)
+    pub owner: Option<UserId>,
+    /// Dexterity + Initiative modifiers
+    pub initiative_bonus: i32,
+    pub initiative: Option<InitiativeRoll>,
+    /// Maximum Hits, if known
+    pub max_hits: Option<i32>,
+    /// Hits taken so far
+    pub hits_lost: i32,
+    /// Defensive Modifier components, before conditions
+    pub defense: DefenseModifiers,
+    /// DM from Parrying this round
+    pub parry: i32,
+    pub conditions: Vec<Condition>,
+}
+
+impl Combatant {
+    pub fn new(name: &str, owner: Option<UserId>, initiative_bonus: i32) -> Self {
+        Combatant {
+            name: name.to_string(),
+            owner,
+            initiative_bonus,
+            initiative: None,
+            max_hits: None,
+            hits_lost: 0,
+            defense: DefenseModifiers::default(),
+            parry: 0,
+            conditions: Vec::new(),
+        }
+    }
+
+    pub fn is_npc(&self) -> bool {
+        self.owner.is_none()
+    }
+
+    /// Remaining Hits, if the maximum is known
+    pub fn current_hits(&self) -> Option<i32> {
+        self.max_hits.map(|max| max - self.hits_lost)
+    }
+
+    /// How badly hurt the combatant is, if the maximum Hits are known
+    pub fn wound_level(&self) -> Option<WoundLevel> {
+        self.max_hits
+            .map(|max| WoundLevel::from_hits(max, self.hits_lost))
+    }
+
+    /// Take Hits, returning the wound level if it got worse
+    pub fn take_hits(&mut self, hits: i32) -> Option<WoundLevel> {
+        let before = self.wound_level();
+        self.hits_lost += hits.max(0);
+        let after = self.wound_level();
+        (after > before).then_some(after).flatten()
+    }
+
+    /// Heal Hits, never past the maximum
+    pub fn heal(&mut self, hits: i32) {
+        self.hits_lost = (self.hits_lost - hits.max(0)).max(0);
+    }
+
+    /// Initiative bonus including the wounded penalty
+    pub fn initiative_modifier(&self) -> i32 {
+        self.initiative_bonus + self.wound_level().map_or(0, |level| level.initiative_modifier())
+    }
+
+    /// Tracked conditions plus the ones wounds bring (Bruised, Incapacitated)
+    pub fn active_conditions(&self) -> Vec<Condition> {
+        let mut active = self.conditions.clone();
+        if let Some(level) = self.wound_level() {
+            for &kind in level.conditions() {
+                if !active.iter().any(|c| c.kind == kind) {
+                    active.push(Condition::new(kind, 0, None));
+                }
+            }
+        }
+        active
+    }
+
+    /// Whether a condition applies, including ones from wounds
+    pub fn has_condition(&self, kind: ConditionKind) -> bool {
+        self.active_conditions().iter().any(|c| c.kind == kind)
+    }
+
+    /// Why the combatant can't Parry, if they can't
+    pub fn parry_restriction(&self) -> Option<&'static str> {
+        if self.has_condition(ConditionKind::Incapacitated) {
+            Some("Incapacitated")
+        } else if self.has_condition(ConditionKind::Held) {
+            Some("Held")
+        } else if self.has_condition(ConditionKind::Surprised) {
+            Some("Surprised")
+        } else {
+            None
+        }
+    }
+
+    /// Defensive Modifier with conditions applied: Held, Prone, and no
+    /// Shield or Parry when Surprised or On Rear
+    pub fn defense_modifiers(&self) -> DefenseModifiers {
+        let has = |kind| self.has_condition(kind);
+        let exposed = has(ConditionKind::Surprised) || has(ConditionKind::OnRear);
+        let parry = if exposed || self.parry_restriction().is_some() {
+            0
+        } else {
+            self.parry
+        };
+        DefenseModifiers {
+            parry,
+            held: has(ConditionKind::Held),
+            prone: has(ConditionKind::Prone),
+            no_shield: exposed,
+            ..self.defense
+        }
+    }
+
+    /// Hits for display, e.g. "12/30 Hits" or "-5 Hits"
+    pub fn format_hits(&self) -> String {
+        match (self.current_hits(), self.max_hits) {
+            (Some(current), Some(max)) => format!("{}/{} Hits", current, max),
+            _ => format!("-{} Hits", self.hits_lost),
+        }
+    }
+
+    /// Add a condition, stacking with one of the same kind
+    pub fn add_condition(&mut self, condition: Condition) {
+        conditions::add_condition(&mut self.conditions, condition);
+    }
+
+    /// Remove a condition, returning whether it was present
+    pub fn remove_condition(&mut self, kind: ConditionKind) -> bool {
+        let before = self.conditions.len();
+        self.conditions.retain(|c| c.kind != kind);
+        self.conditions.len() != before
+    }
+
+    /// Condition penalties to a roll of `kind`
+    pub fn roll_penalties(&self, kind: RollKind) -> RollPenalties {
+        conditions::roll_penalties(&self.active_conditions(), kind)
+    }
+
+    /// Condition penalties by kind of roll, e.g. "Attack -8 | Save -4", if any
+    pub fn penalty_summary(&self) -> Option<String> {
+        let parts: Vec<String> = [RollKind::Skill, RollKind::Attack, RollKind::Save, RollKind::Cast]
+            .into_iter()
+            .map(|kind| (kind, self.roll_penalties(kind).total()))
+            .filter(|(_, total)| *total != 0)
+            .map(|(kind, total)| format!("{} {:+}", kind.name(), total))
+            .collect();
+        if parts.is_empty() {
+            None
+        } else {
+            Some(parts.join(" | "))
+        }
+    }
+
+    /// Conditions with their effects, one per line
+    pub fn format_conditions(&self) -> String {
+        let conditions = self.active_conditions();
+        if conditions.is_empty() {
+            return format!("**{}**: no conditions", self.name);
+        }
+        let mut output = format!("**{}**", self.name);
+        for condition in &conditions {
+            output.push_str(&format!("\n• {} — {}", condition, condition.kind.effect()));
+        }
+        if let Some(summary) = self.penalty_summary() {
+            output.push_str(&format!("\n⚠️ Penalties: {}", summary));
+        }
+        output
+    }
+}
+
+/// A combat encounter in one channel
+#[derive(Debug, Clone, Default)]
+pub struct Encounter {
+    /// Combatants in Initiative order once rolled
+    pub combatants: Vec<Combatant>,
+    pub round: u32,
+    pub phase: Phase,
+    /// Index of the acting combatant during Phase 3
+    pub turn: usize,
+    /// Tracker message that gets edited in place
+    pub message_id: Option<MessageId>,
+    /// Last round whose Upkeep has been applied
+    pub upkeep_round: Option<u32>,
+}
+
+impl Encounter {
+    /// Start a new encounter at Round 1, Phase 1
+    pub fn new() -> Self {
+        Encounter {
+            round: 1,
+            ..Default::default()
+        }
+    }
+
+    /// Find a combatant by name, ignoring case
+    pub fn find(&self, name: &str) -> Option<&Combatant> {
+        self.combatants
+            .iter()
+            .find(|c| c.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Find a combatant by name for changes, ignoring case
+    pub fn find_mut(&mut self, name: &str) -> Option<&mut Combatant> {
+        self.combatants
+            .iter_mut()
+            .find(|c| c.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Add a combatant; names must be unique within the encounter
+    pub fn add(&mut self, combatant: Combatant) -> Result<(), String> {
+        if self.find(&combatant.name).is_some() {
+            return Err(format!("**{}** is already in this combat", combatant.name));
+        }
+        self.combatants.push(combatant);
+        Ok(())
+    }
+
+    /// Roll Initiative for everyone and sort highest first
+    ///
+    /// Wounded combatants take -10. Ties go to the higher Initiative bonus,
+    /// then keep their joining order.
+    pub fn roll_initiative(&mut self, dice: &mut dyn DiceSource) {
+        for combatant in &mut self.combatants {
+            let (d1, d2, dice_total) = roll_2d10_closed(dice);
+            combatant.initiative = Some(InitiativeRoll {
+                dice: (d1, d2),
+                total: dice_total + combatant.initiative_modifier(),
+            });
+        }
+        self.combatants.sort_by_key(|c| {
+            let total = c.initiative.map_or(i32::MIN, |roll| roll.total);
+            std::cmp::Reverse((total, c.initiative_modifier()))
+        });
+        self.turn = 0;
+    }
+
+    /// Combatant whose action is being resolved, during Phase 3
+    pub fn current(&self) -> Option<&Combatant> {
+        match self.phase {
+            Phase::Resolve => self.combatants.get(self.turn),
+            _ => None,
+        }
+    }
+
+    /// Advance to the next phase or turn, starting a new round after the last action
+    pub fn next(&mut self) {
+        match self.phase {
+            Phase::Upkeep => self.phase = Phase::Declare,
+            Phase::Declare => {
+                self.phase = Phase::Resolve;
+                self.turn = 0;
+            }
+            Phase::Resolve if self.turn + 1 < self.combatants.len() => self.turn += 1,
+            Phase::Resolve => {
+                self.round += 1;
+                self.phase = Phase::Upkeep;
+                self.turn = 0;
+                // Parrying only lasts for the round it was declared
+                for combatant in &mut self.combatants {
+                    combatant.parry = 0;
+                }
+            }
+        }
+    }
+
+    /// Run the Upkeep step for every combatant: bleeding, then durations
+    ///
+    /// Each combatant's result comes with their wound level if bleeding made it
+    /// worse. Upkeep happens once a round; running it again is an error.
+    pub fn upkeep(&mut self) -> Result<Vec<(String, UpkeepResult, Option<WoundLevel>)>, String> {
+        if self.upkeep_round == Some(self.round) {
+            return Err(format!("Upkeep has already been run for round {}", self.round));
+        }
+        self.upkeep_round = Some(self.round);
+        Ok(self
+            .combatants
+            .iter_mut()
+            .map(|combatant| {
+                let result = conditions::upkeep(&mut combatant.conditions);
+                let worsened = combatant.take_hits(result.bleeding);
+                (combatant.name.clone(), result, worsened)
+            })
+            .collect())
+    }
+
+    /// Format the tracker message for Discord display
+    pub fn format_tracker(&self) -> String {
+        let phase = self.phase;
+        let mut output = format!(
+            "⚔️ **Combat — Round {}**\n━━━━━━━━━━━━━━\n**Phase {}: {}**\n*{}*\n━━━━━━━━━━━━━━",
+            self.round,
+            phase.number(),
+            phase.name(),
+            phase.description()
+        );
+
+        if phase == Phase::Upkeep && self.upkeep_round != Some(self.round) {
+            output.push_str("\n🩺 Run `/upkeep` to apply bleeding and tick down conditions");
+        }
+        if self.combatants.is_empty() {
+            output.push_str("\nNo combatants yet. Use `/combat add` or `/combat join`.");
+        }
+        for (i, combatant) in self.combatants.iter().enumerate() {
+            let marker = if phase == Phase::Resolve && i == self.turn {
+                "▶️"
+            } else {
+                "▫️"
+            };
+            let npc = if combatant.is_npc() { " (NPC)" } else { "" };
+            let initiative = match combatant.initiative {
+                Some(roll) => format!(
+                    "**{}** ([{}, {}] {:+})",
+                    roll.total, roll.dice.0, roll.dice.1, combatant.initiative_modifier()
+                ),
+                None => format!("not rolled ({:+})", combatant.initiative_modifier()),
+            };
+            output.push_str(&format!(
+                "\n{} {}. {}{} — {}",
+                marker,
+                i + 1,
+                combatant.name,
+                npc,
+                initiative
+            ));
+            if combatant.max_hits.is_some() || combatant.hits_lost != 0 {
+                output.push_str(&format!(" | {}", combatant.format_hits()));
+            }
+            match combatant.wound_level() {
+                Some(WoundLevel::Dying) => output.push_str(" 💀 Dying"),
+                Some(WoundLevel::Wounded) => output.push_str(" 🩸"),
+                _ => {}
+            }
+            if combatant.parry != 0 {
+                output.push_str(&format!(" | Parry {:+} DM", combatant.parry));
+            }
+            let active = combatant.active_conditions();
+            if !active.is_empty() {
+                let conditions: Vec<String> = active.iter().map(|c| c.to_string()).collect();
+                output.push_str(&format!(" | {}", conditions.join(", ")));
+            }
+        }
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dice::source::ScriptedSource;
+
+    fn encounter(bonuses: &[(&str, i32)]) -> Encounter {
+        let mut encounter = Encounter::new();
+        for (name, bonus) in bonuses {
+            encounter.add(Combatant::new(name, None, *bonus)).unwrap();
+        }
+        encounter
+    }
+
+    #[test]
+    fn test_duplicate_names_rejected() {
+        let mut encounter = encounter(&[("Goblin", 1)]);
+        assert!(encounter.add(Combatant::new("goblin", None, 2)).is_err());
+        assert_eq!(encounter.combatants.len(), 1);
+    }
+
+    #[test]
+    fn test_initiative_sorted_highest_first() {
+        let mut encounter = encounter(&[("Aria", 3), ("Goblin", 1), ("Bram", 0)]);
+        let mut dice = ScriptedSource::new(&[2, 3, 9, 9, 6, 6]);
+        encounter.roll_initiative(&mut dice);
+
+        let order: Vec<(&str, i32)> = encounter
+            .combatants
+            .iter()
+            .map(|c| (c.name.as_str(), c.initiative.unwrap().total))
+            .collect();
+        assert_eq!(order, vec![("Goblin", 19), ("Bram", 12), ("Aria", 8)]);
+    }
+
+    #[test]
+    fn test_ties_go_to_higher_bonus() {
+        let mut encounter = encounter(&[("Goblin", 1), ("Aria", 3)]);
+        let mut dice = ScriptedSource::new(&[5, 5, 4, 4]);
+        encounter.roll_initiative(&mut dice);
+        assert_eq!(encounter.combatants[0].name, "Aria");
+    }
+
+    #[test]
+    fn test_upkeep_bleeds_and_marks_round() {
+        let mut encounter = encounter(&[("Aria", 0)]);
+        encounter.combatants[0].add_condition(Condition::new(ConditionKind::Bleed, 2, None));
+        let report = encounter.upkeep().unwrap();
+        assert_eq!(report[0].1.bleeding, 2);
+        assert_eq!(encounter.combatants[0].hits_lost, 2);
+        assert_eq!(encounter.upkeep_round, Some(1));
+    }
+
+    #[test]
+    fn test_upkeep_reports_wound_thresholds() {
+        let mut encounter = encounter(&[("Aria", 0), ("Goblin", 0)]);
+        encounter.combatants[0].max_hits = Some(10);
+        encounter.combatants[0].hits_lost = 8;
+        encounter.combatants[0].add_condition(Condition::new(ConditionKind::Bleed, 3, None));
+        encounter.combatants[1].max_hits = Some(10);
+        encounter.combatants[1].hits_lost = 9;
+        encounter.combatants[1].add_condition(Condition::new(ConditionKind::Bleed, 1, None));
+
+        let report = encounter.upkeep().unwrap();
+        assert_eq!(report[0].2, Some(WoundLevel::Dying));
+        assert_eq!(report[1].2, Some(WoundLevel::Incapacitated));
+    }
+
+    #[test]
+    fn test_upkeep_once_per_round() {
+        let mut encounter = encounter(&[("Aria", 0)]);
+        encounter.combatants[0].add_condition(Condition::new(ConditionKind::Bleed, 2, None));
+        encounter.upkeep().unwrap();
+        assert!(encounter.upkeep().is_err());
+        assert_eq!(encounter.combatants[0].hits_lost, 2);
+
+        // A new round gets its own upkeep
+        for _ in 0..3 {
+            encounter.next();
+        }
+        assert_eq!(encounter.round, 2);
+        encounter.upkeep().unwrap();
+        assert_eq!(encounter.combatants[0].hits_lost, 4);
+    }
+
+    #[test]
+    fn test_wounds_bruise_and_slow_initiative() {
+        let mut encounter = encounter(&[("Aria", 3)]);
+        let aria = &mut encounter.combatants[0];
+        aria.max_hits = Some(20);
+        assert_eq!(aria.take_hits(4), Some(WoundLevel::Hurt));
+        assert_eq!(aria.take_hits(1), None);
+        assert_eq!(aria.take_hits(5), Some(WoundLevel::Bruised));
+        assert_eq!(aria.initiative_modifier(), 3);
+        assert_eq!(aria.roll_penalties(RollKind::Skill).total(), -4);
+        assert_eq!(aria.roll_penalties(RollKind::Save).total(), 0);
+        assert_eq!(aria.take_hits(1), Some(WoundLevel::Wounded));
+        assert_eq!(aria.initiative_modifier(), 3 - 10);
+        assert_eq!(aria.roll_penalties(RollKind::Skill).total(), -4);
+
+        aria.heal(100);
+        assert_eq!(aria.current_hits(), Some(20));
+        assert!(aria.active_conditions().is_empty());
+    }
+
+    #[test]
+    fn test_conditions_lower_defense() {
+        let mut encounter = encounter(&[("Goblin", 0)]);
+        let goblin = &mut encounter.combatants[0];
+        goblin.defense.dexterity = 2;
+        goblin.defense.shield = 3;
+        assert_eq!(goblin.defense_modifiers().total(), 5);
+
+        goblin.parry = 4;
+        assert_eq!(goblin.defense_modifiers().total(), 9);
+
+        goblin.add_condition(Condition::new(ConditionKind::OnRear, 0, None));
+        goblin.add_condition(Condition::new(ConditionKind::Held, 0, None));
+        assert_eq!(goblin.defense_modifiers().total(), 2 - 6);
+        assert_eq!(goblin.parry_restriction(), Some("Held"));
+    }
+
+    #[test]
+    fn test_round_sequence() {
+        let mut encounter = encounter(&[("Aria", 0), ("Goblin", 0)]);
+        assert_eq!((encounter.round, encounter.phase), (1, Phase::Upkeep));
+        assert!(encounter.current().is_none());
+
+        encounter.next();
+        assert_eq!(encounter.phase, Phase::Declare);
+        encounter.next();
+        assert_eq!(encounter.phase, Phase::Resolve);
+        assert_eq!(encounter.current().unwrap().name, "Aria");
+        encounter.next();
+        assert_eq!(encounter.current().unwrap().name, "Goblin");
+        encounter.next();
+        assert_eq!((encounter.round, encounter.phase), (2, Phase::Upkeep));
+    }
+}
@@ -0,0 +1,24 @@
+//! Combat encounter tracking: initiative, conditions and wounds
+//!
+//! Encounters live in memory, one per channel, for the lifetime of the bot.
+
+pub mod conditions;
+pub mod encounter;
+pub mod wounds;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serenity::all::ChannelId;
+use serenity::prelude::{RwLock, TypeMapKey};
+
+pub use conditions::{Condition, ConditionKind};
+pub use encounter::{Combatant, Encounter};
+pub use wounds::WoundLevel;
+
+/// Running encounters by channel, in the client's data map
+pub struct EncounterStoreKey;
+
+impl TypeMapKey for EncounterStoreKey {
+    type Value = Arc<RwLock<HashMap<ChannelId, Encounter>>>;
+}
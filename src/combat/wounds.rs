@@ -0,0 +1,106 @@
+//! Hits and wound thresholds
+//!
+//! Losing half your Hits or more leaves you Bruised, and losing more than
+//! half also slows Initiative (-10). At 0 Hits you're Incapacitated, and below 0 you're Dying. The rules
+//! don't say how long a character has once Dying, so the GM adds Dying(#)
+//! with `/condition` when the critical or the situation calls for it.
+
+use super::conditions::ConditionKind;
+
+/// Initiative modifier for having lost more than 50% of your Hits
+pub const WOUNDED_INITIATIVE_PENALTY: i32 = -10;
+
+/// How badly hurt a combatant is, least to most
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WoundLevel {
+    Unhurt,
+    /// Lost less than half their Hits
+    Hurt,
+    /// Lost exactly half their Hits: Bruised
+    Bruised,
+    /// Lost more than half their Hits: Bruised, -10 Initiative
+    Wounded,
+    /// At 0 Hits
+    Incapacitated,
+    /// Below 0 Hits
+    Dying,
+}
+
+impl WoundLevel {
+    /// Wound level for `hits_lost` out of `max_hits`
+    pub fn from_hits(max_hits: i32, hits_lost: i32) -> Self {
+        let remaining = max_hits - hits_lost;
+        if remaining < 0 {
+            WoundLevel::Dying
+        } else if remaining == 0 {
+            WoundLevel::Incapacitated
+        } else if hits_lost * 2 > max_hits {
+            WoundLevel::Wounded
+        } else if hits_lost * 2 == max_hits {
+            WoundLevel::Bruised
+        } else if hits_lost > 0 {
+            WoundLevel::Hurt
+        } else {
+            WoundLevel::Unhurt
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            WoundLevel::Unhurt => "Unhurt",
+            WoundLevel::Hurt => "Hurt",
+            WoundLevel::Bruised => "Bruised ≥50%",
+            WoundLevel::Wounded => "Wounded >50%",
+            WoundLevel::Incapacitated => "Incapacitated",
+            WoundLevel::Dying => "Dying",
+        }
+    }
+
+    /// Conditions the wound level brings with it
+    pub fn conditions(&self) -> &'static [ConditionKind] {
+        match self {
+            WoundLevel::Unhurt | WoundLevel::Hurt => &[],
+            WoundLevel::Bruised | WoundLevel::Wounded => &[ConditionKind::Bruised],
+            WoundLevel::Incapacitated | WoundLevel::Dying => {
+                &[ConditionKind::Bruised, ConditionKind::Incapacitated]
+            }
+        }
+    }
+
+    /// Initiative modifier from wounds
+    pub fn initiative_modifier(&self) -> i32 {
+        if *self >= WoundLevel::Wounded {
+            WOUNDED_INITIATIVE_PENALTY
+        } else {
+            0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thresholds() {
+        assert_eq!(WoundLevel::from_hits(30, 0), WoundLevel::Unhurt);
+        assert_eq!(WoundLevel::from_hits(30, 14), WoundLevel::Hurt);
+        assert_eq!(WoundLevel::from_hits(30, 15), WoundLevel::Bruised);
+        assert_eq!(WoundLevel::from_hits(30, 16), WoundLevel::Wounded);
+        assert_eq!(WoundLevel::from_hits(31, 15), WoundLevel::Hurt);
+        assert_eq!(WoundLevel::from_hits(31, 16), WoundLevel::Wounded);
+        assert_eq!(WoundLevel::from_hits(30, 30), WoundLevel::Incapacitated);
+        assert_eq!(WoundLevel::from_hits(30, 31), WoundLevel::Dying);
+    }
+
+    #[test]
+    fn test_wounded_effects() {
+        assert_eq!(WoundLevel::Hurt.initiative_modifier(), 0);
+        assert_eq!(WoundLevel::Bruised.initiative_modifier(), 0);
+        assert_eq!(WoundLevel::Bruised.conditions(), &[ConditionKind::Bruised]);
+        assert_eq!(WoundLevel::Wounded.initiative_modifier(), -10);
+        assert_eq!(WoundLevel::Dying.initiative_modifier(), -10);
+        assert_eq!(WoundLevel::Wounded.conditions(), &[ConditionKind::Bruised]);
+    }
+}
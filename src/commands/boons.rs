@@ -0,0 +1,187 @@
+//! Boon spending - a select menu on successful rolls for spending Success Levels
+//!
+//! Each purchase edits the roll's message to show what was bought and the SL
+//! left. Open menus are kept in memory by message until the SL run out, the
+//! roller is done or the menu expires.
+
+use std::sync::Arc;
+
+use serenity::all::{
+    ButtonStyle, CommandInteraction, ComponentInteraction, ComponentInteractionDataKind, Context,
+    CreateActionRow, CreateButton, CreateInteractionResponse, CreateInteractionResponseMessage,
+    CreateSelectMenu, CreateSelectMenuKind, CreateSelectMenuOption, UserId,
+};
+use serenity::prelude::{RwLock, TypeMapKey};
+
+use super::message_store::MessageStore;
+use super::{send_component_ephemeral, send_response};
+use crate::dice::boons::BoonSpend;
+use crate::dice::feat::RollKind;
+use crate::dice::SeededSource;
+
+/// Custom ID of the boon select menu
+pub const BOON_MENU_ID: &str = "boon_spend";
+
+/// Custom ID of the button that closes the menu
+pub const BOON_DONE_ID: &str = "boon_done";
+
+/// Most options a Discord select menu can hold
+const MAX_MENU_OPTIONS: usize = 25;
+
+/// A roll whose Success Levels are being spent
+pub struct BoonSession {
+    /// Only the roller may spend
+    user: UserId,
+    /// The roll's message, without the seed line
+    content: String,
+    seed: u64,
+    spend: BoonSpend,
+}
+
+impl BoonSession {
+    /// The roll's message with the purchases so far
+    fn render(&self) -> String {
+        if self.spend.bought.is_empty() {
+            format!("{}\n-# 🌱 Seed: {}", self.content, self.seed)
+        } else {
+            format!(
+                "{}\n━━━━━━━━━━━━━━\n{}\n-# 🌱 Seed: {}",
+                self.content,
+                self.spend.summary(),
+                self.seed
+            )
+        }
+    }
+}
+
+/// Open boon menus, by the message holding them
+pub struct BoonStoreKey;
+
+impl TypeMapKey for BoonStoreKey {
+    type Value = Arc<RwLock<MessageStore<BoonSession>>>;
+}
+
+/// Send a roll response, with a boon menu if it has Success Levels to spend
+pub async fn send_roll_with_boons(
+    ctx: &Context,
+    command: &CommandInteraction,
+    content: &str,
+    dice: &SeededSource,
+    kind: RollKind,
+    success_levels: u8,
+) {
+    let session = BoonSession {
+        user: command.user.id,
+        content: content.to_string(),
+        seed: dice.seed(),
+        spend: match BoonSpend::new(kind, success_levels) {
+            Some(spend) if !spend.affordable().is_empty() => spend,
+            _ => {
+                let content = format!("{}\n-# 🌱 Seed: {}", content, dice.seed());
+                send_response(ctx, command, &content).await;
+                return;
+            }
+        },
+    };
+
+    let message = CreateInteractionResponseMessage::new()
+        .content(session.render())
+        .components(boon_components(&session.spend));
+    if let Err(e) = command
+        .create_response(&ctx.http, CreateInteractionResponse::Message(message))
+        .await
+    {
+        eprintln!("Error sending response: {:?}", e);
+        return;
+    }
+
+    match command.get_response(&ctx.http).await {
+        Ok(response) => {
+            if let Some(store) = boon_store(ctx).await {
+                store.write().await.insert(response.id, session);
+            }
+        }
+        Err(e) => eprintln!("Error fetching roll response: {:?}", e),
+    }
+}
+
+/// Handle the boon menu and its Done button
+pub async fn handle_boon_component(ctx: &Context, component: &ComponentInteraction) {
+    let Some(store) = boon_store(ctx).await else {
+        return;
+    };
+    let mut sessions = store.write().await;
+    let Some(session) = sessions.get_mut(&component.message.id) else {
+        drop(sessions);
+        send_component_ephemeral(ctx, component, "⚠️ These Success Levels have already been spent or have expired")
+            .await;
+        return;
+    };
+    if session.user != component.user.id {
+        drop(sessions);
+        send_component_ephemeral(ctx, component, "⚠️ Only the roller can spend these Success Levels")
+            .await;
+        return;
+    }
+
+    if let ComponentInteractionDataKind::StringSelect { values } = &component.data.kind {
+        let index = values.first().and_then(|value| value.parse::<usize>().ok());
+        if let Err(message) = index
+            .ok_or_else(|| "⚠️ No such boon".to_string())
+            .and_then(|index| session.spend.buy(index))
+        {
+            drop(sessions);
+            send_component_ephemeral(ctx, component, &message).await;
+            return;
+        }
+    }
+
+    let done = component.data.custom_id == BOON_DONE_ID || session.spend.affordable().is_empty();
+    let mut message = CreateInteractionResponseMessage::new().content(session.render());
+    message = if done {
+        sessions.remove(&component.message.id);
+        message.components(vec![])
+    } else {
+        message.components(boon_components(&session.spend))
+    };
+    drop(sessions);
+
+    if let Err(e) = component
+        .create_response(&ctx.http, CreateInteractionResponse::UpdateMessage(message))
+        .await
+    {
+        eprintln!("Error updating boons: {:?}", e);
+    }
+}
+
+/// Get the shared store of open boon menus
+async fn boon_store(ctx: &Context) -> Option<Arc<RwLock<MessageStore<BoonSession>>>> {
+    ctx.data.read().await.get::<BoonStoreKey>().cloned()
+}
+
+/// Select menu of the boons that can still be bought, and the Done button
+fn boon_components(spend: &BoonSpend) -> Vec<CreateActionRow> {
+    let options = spend
+        .affordable()
+        .into_iter()
+        .take(MAX_MENU_OPTIONS)
+        .map(|index| {
+            let boon = &spend.table[index];
+            CreateSelectMenuOption::new(
+                format!("{} ({} SL)", boon.name, spend.next_cost(index)),
+                index.to_string(),
+            )
+            .description(boon.effect)
+        })
+        .collect();
+    let menu = CreateSelectMenu::new(BOON_MENU_ID, CreateSelectMenuKind::String { options })
+        .placeholder(format!("Spend {} SL on {} Boons", spend.remaining(), spend.kind.name()));
+    let done = CreateButton::new(BOON_DONE_ID)
+        .label("Done")
+        .style(ButtonStyle::Secondary);
+
+    vec![
+        CreateActionRow::SelectMenu(menu),
+        CreateActionRow::Buttons(vec![done]),
+    ]
+}
@@ -0,0 +1,128 @@
+//! Casting Partial Success - buttons for the caster to pick the cost
+//!
+//! The choice is written into the message itself, so nothing is kept in memory.
+
+use serenity::all::{
+    ButtonStyle, CommandInteraction, ComponentInteraction, Context, CreateActionRow, CreateButton,
+    CreateInteractionResponse, CreateInteractionResponseMessage, Message,
+    MessageInteractionMetadata, UserId,
+};
+
+use super::send_component_ephemeral;
+use crate::dice::SeededSource;
+
+/// What the caster makes of a Partial Success
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartialCasting {
+    /// The spell fails, but no Mana is spent
+    Fail,
+    /// The spell works at half effect
+    HalfEffect,
+    /// The spell works, for double the Mana
+    DoubleMana,
+}
+
+impl PartialCasting {
+    /// All choices, in rules order
+    pub const ALL: [PartialCasting; 3] = [
+        PartialCasting::Fail,
+        PartialCasting::HalfEffect,
+        PartialCasting::DoubleMana,
+    ];
+
+    /// Parse a button's custom ID
+    pub fn from_custom_id(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.custom_id() == value)
+    }
+
+    /// Custom ID of the choice's button
+    pub fn custom_id(&self) -> &'static str {
+        match self {
+            PartialCasting::Fail => "cast_partial_fail",
+            PartialCasting::HalfEffect => "cast_partial_half",
+            PartialCasting::DoubleMana => "cast_partial_double",
+        }
+    }
+
+    /// Button label
+    pub fn label(&self) -> &'static str {
+        match self {
+            PartialCasting::Fail => "Fail, no Mana",
+            PartialCasting::HalfEffect => "Half effect",
+            PartialCasting::DoubleMana => "Double Mana",
+        }
+    }
+
+    /// What happens to the spell
+    pub fn outcome(&self) -> &'static str {
+        match self {
+            PartialCasting::Fail => "the spell fails, but no Mana is spent",
+            PartialCasting::HalfEffect => "the spell works at half effect",
+            PartialCasting::DoubleMana => "the spell works at full effect for double the Mana",
+        }
+    }
+}
+
+/// Send a Partial Success casting roll with a button for each choice
+pub async fn send_partial_cast(
+    ctx: &Context,
+    command: &CommandInteraction,
+    content: &str,
+    dice: &SeededSource,
+) {
+    let buttons = PartialCasting::ALL
+        .into_iter()
+        .map(|choice| {
+            CreateButton::new(choice.custom_id())
+                .label(choice.label())
+                .style(ButtonStyle::Primary)
+        })
+        .collect();
+    let message = CreateInteractionResponseMessage::new()
+        .content(format!("{}\n-# 🌱 Seed: {}", content, dice.seed()))
+        .components(vec![CreateActionRow::Buttons(buttons)]);
+
+    if let Err(e) = command
+        .create_response(&ctx.http, CreateInteractionResponse::Message(message))
+        .await
+    {
+        eprintln!("Error sending response: {:?}", e);
+    }
+}
+
+/// Handle a Partial Success choice button - record the choice and remove the buttons
+pub async fn handle_partial_cast(ctx: &Context, component: &ComponentInteraction) {
+    let Some(choice) = PartialCasting::from_custom_id(&component.data.custom_id) else {
+        return;
+    };
+    if invoked_by(&component.message).is_some_and(|caster| caster != component.user.id) {
+        send_component_ephemeral(ctx, component, "⚠️ Only the caster can choose").await;
+        return;
+    }
+
+    // Keep the seed line last
+    let content = &component.message.content;
+    let (body, seed) = content.rsplit_once("\n-# 🌱").unwrap_or((content, ""));
+    let mut updated = format!("{}\n🪄 **{}**: {}", body, choice.label(), choice.outcome());
+    if !seed.is_empty() {
+        updated.push_str(&format!("\n-# 🌱{}", seed));
+    }
+
+    let message = CreateInteractionResponseMessage::new()
+        .content(updated)
+        .components(vec![]);
+    if let Err(e) = component
+        .create_response(&ctx.http, CreateInteractionResponse::UpdateMessage(message))
+        .await
+    {
+        eprintln!("Error updating casting roll: {:?}", e);
+    }
+}
+
+/// User who ran the slash command a message answers
+fn invoked_by(message: &Message) -> Option<UserId> {
+    match message.interaction_metadata.as_deref()? {
+        MessageInteractionMetadata::Command(metadata) => Some(metadata.user.id),
+        _ => None,
+    }
+}
@@ -0,0 +1,319 @@
+//! /char command - persistent character sheets
+
+use std::sync::Arc;
+
+use serenity::all::{
+    CommandDataOption, CommandDataOptionValue, CommandInteraction, CommandOptionType, Context,
+    CreateCommand, CreateCommandOption,
+};
+use serenity::prelude::RwLock;
+
+use super::{send_ephemeral, send_response};
+use crate::character::{Character, CharacterStore, CharacterStoreKey};
+use crate::dice::save::SaveType;
+
+/// Get the shared character store
+async fn character_store(ctx: &Context) -> Option<Arc<RwLock<CharacterStore>>> {
+    ctx.data.read().await.get::<CharacterStoreKey>().cloned()
+}
+
+/// Look up the invoking user's character sheet
+pub async fn find_character(ctx: &Context, command: &CommandInteraction) -> Option<Character> {
+    let store = character_store(ctx).await?;
+    let store = store.read().await;
+    store.get(command.guild_id, command.user.id).cloned()
+}
+
+/// Handle the /char command - create, edit and show character sheets
+pub async fn handle_char(ctx: &Context, command: &CommandInteraction) {
+    let Some(subcommand) = command.data.options.first() else {
+        return;
+    };
+    let CommandDataOptionValue::SubCommand(options) = &subcommand.value else {
+        return;
+    };
+    let Some(store) = character_store(ctx).await else {
+        send_ephemeral(ctx, command, "⚠️ Character sheets are not available").await;
+        return;
+    };
+
+    let result = match subcommand.name.as_str() {
+        "create" => create_character(command, options, &store).await,
+        "set" => set_character(command, options, &store).await,
+        "show" => show_character(command, options, &store).await,
+        _ => return,
+    };
+
+    match result {
+        Ok(content) => send_response(ctx, command, &content).await,
+        Err(message) => send_ephemeral(ctx, command, &message).await,
+    }
+}
+
+/// Store the invoking user's sheet and write it to disk, reporting failure to the user
+fn save_character(
+    store: &mut CharacterStore,
+    command: &CommandInteraction,
+    character: Character,
+) -> Result<(), String> {
+    store.save_character(command.guild_id, command.user.id, character).map_err(|e| {
+        eprintln!("Error saving character store: {:?}", e);
+        "⚠️ Couldn't save the character sheet, please try again".to_string()
+    })
+}
+
+async fn create_character(
+    command: &CommandInteraction,
+    options: &[CommandDataOption],
+    store: &RwLock<CharacterStore>,
+) -> Result<String, String> {
+    let mut name = String::new();
+    let mut level: i32 = 1;
+    let mut replace = false;
+
+    for option in options {
+        match option.name.as_str() {
+            "name" => {
+                if let Some(val) = option.value.as_str() {
+                    name = val.trim().to_string();
+                }
+            }
+            "level" => {
+                if let Some(val) = option.value.as_i64() {
+                    level = val as i32;
+                }
+            }
+            "replace" => {
+                if let Some(val) = option.value.as_bool() {
+                    replace = val;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut store = store.write().await;
+    if let Some(existing) = store.get(command.guild_id, command.user.id) {
+        if !replace {
+            return Err(format!(
+                "⚠️ You already have a character (**{}**). Use `/char set` to edit it, or `replace:True` to start over.",
+                existing.name
+            ));
+        }
+    }
+
+    let character = Character::new(&name, level);
+    let sheet = character.format_sheet();
+    save_character(&mut store, command, character)?;
+
+    Ok(format!("✅ Character created\n{}", sheet))
+}
+
+async fn set_character(
+    command: &CommandInteraction,
+    options: &[CommandDataOption],
+    store: &RwLock<CharacterStore>,
+) -> Result<String, String> {
+    let mut field = String::new();
+    let mut name: Option<String> = None;
+    let mut value: i32 = 0;
+
+    for option in options {
+        match option.name.as_str() {
+            "field" => {
+                if let Some(val) = option.value.as_str() {
+                    field = val.to_string();
+                }
+            }
+            "name" => {
+                if let Some(val) = option.value.as_str() {
+                    name = Some(val.trim().to_string());
+                }
+            }
+            "value" => {
+                if let Some(val) = option.value.as_i64() {
+                    value = val as i32;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut store = store.write().await;
+    // Edit a copy, so a failed save leaves the stored sheet untouched
+    let Some(mut character) = store.get(command.guild_id, command.user.id).cloned() else {
+        return Err("⚠️ You don't have a character yet. Use `/char create` first.".to_string());
+    };
+
+    let needs_name = || {
+        name.clone()
+            .filter(|n| !n.is_empty())
+            .ok_or_else(|| format!("⚠️ Setting a {} needs a `name`", field))
+    };
+    let updated = match field.as_str() {
+        "stat" => {
+            let name = needs_name()?;
+            character.set_stat(&name, value);
+            format!("{} {:+}", name, value)
+        }
+        "skill" => {
+            let name = needs_name()?;
+            character.set_skill(&name, value);
+            format!("{} {:+}", name, value)
+        }
+        "attack" => {
+            let name = needs_name()?;
+            character.set_attack(&name, value);
+            format!("{} Attack Bonus {:+}", name, value)
+        }
+        "kin" => {
+            let save = SaveType::from_choice(&needs_name()?)
+                .ok_or("⚠️ Kin modifiers are set per save: name must be TSR, RSR or WSR")?;
+            character.set_kin(save, value);
+            format!("{} Kin Modifier {:+}", save.name(), value)
+        }
+        "level" => {
+            character.level = value;
+            format!("Level {}", value)
+        }
+        "hits" => {
+            character.hits = value;
+            format!("{} Hits", value)
+        }
+        "armor_penalty" => {
+            character.armor_penalty = value;
+            format!("Armor Dexterity penalty {}", value)
+        }
+        "armor_quality" => {
+            character.armor_quality = value;
+            format!("Armor Quality {:+} DM", value)
+        }
+        "shield" => {
+            character.shield = value;
+            format!("Shield {:+} DM", value)
+        }
+        "magic_dm" => {
+            character.magic_dm = value;
+            format!("Magic {:+} DM", value)
+        }
+        "dm_other" => {
+            character.dm_other = value;
+            format!("Other DM {:+}", value)
+        }
+        "dr" => {
+            character.dr = value;
+            format!("DR {}", value)
+        }
+        _ => return Err("⚠️ Unknown field".to_string()),
+    };
+    let character_name = character.name.clone();
+    save_character(&mut store, command, character)?;
+
+    Ok(format!("✏️ **{}**: {}", character_name, updated))
+}
+
+async fn show_character(
+    command: &CommandInteraction,
+    options: &[CommandDataOption],
+    store: &RwLock<CharacterStore>,
+) -> Result<String, String> {
+    let user_id = options
+        .iter()
+        .find(|opt| opt.name == "user")
+        .and_then(|opt| opt.value.as_user_id())
+        .unwrap_or(command.user.id);
+
+    let store = store.read().await;
+    store
+        .get(command.guild_id, user_id)
+        .map(Character::format_sheet)
+        .ok_or_else(|| "⚠️ No character sheet found. Use `/char create` first.".to_string())
+}
+
+/// Register the /char command
+pub fn register_char() -> CreateCommand {
+    CreateCommand::new("char")
+        .description("Create, edit and show your character sheet")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "create",
+                "Create your character sheet",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(CommandOptionType::String, "name", "Character name")
+                    .required(true)
+                    .max_length(64),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "level",
+                    "Character level (default 1)",
+                )
+                .required(false)
+                .min_int_value(0)
+                .max_int_value(50),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Boolean,
+                    "replace",
+                    "Replace your existing character sheet",
+                )
+                .required(false),
+            ),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "set",
+                "Set a value on your character sheet",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(CommandOptionType::String, "field", "What to set")
+                    .required(true)
+                    .add_string_choice("Stat (name: e.g. Dexterity)", "stat")
+                    .add_string_choice("Skill bonus (name: e.g. Stealth)", "skill")
+                    .add_string_choice("Attack Bonus (name: weapon)", "attack")
+                    .add_string_choice("Save Kin Modifier (name: TSR, RSR or WSR)", "kin")
+                    .add_string_choice("Level", "level")
+                    .add_string_choice("Maximum Hits", "hits")
+                    .add_string_choice("Armor Dexterity penalty (DM)", "armor_penalty")
+                    .add_string_choice("Armor Quality bonus (DM)", "armor_quality")
+                    .add_string_choice("Shield bonus (DM)", "shield")
+                    .add_string_choice("Magic item bonus (DM)", "magic_dm")
+                    .add_string_choice("Other DM bonus", "dm_other")
+                    .add_string_choice("Damage Rating (DR)", "dr"),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(CommandOptionType::Integer, "value", "New value")
+                    .required(true)
+                    .max_int_value(500),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "name",
+                    "Stat, skill, weapon or save name",
+                )
+                .required(false)
+                .max_length(64),
+            ),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "show",
+                "Show a character sheet",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::User,
+                    "user",
+                    "Whose sheet to show (default: yours)",
+                )
+                .required(false),
+            ),
+        )
+}
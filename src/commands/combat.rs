@@ -0,0 +1,847 @@
+//! /combat command - per-channel encounter and initiative tracker
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use serenity::all::{
+    ChannelId, CommandDataOption, CommandDataOptionValue, CommandInteraction, CommandOptionType,
+    Context, CreateCommand, CreateCommandOption, CreateMessage, EditMessage, MessageId,
+};
+use serenity::prelude::RwLock;
+
+use super::character::find_character;
+use super::{dice_source, seed_option, send_ephemeral, send_response};
+use crate::character::Character;
+use crate::combat::conditions::RollPenalties;
+use crate::combat::{
+    Combatant, Condition, ConditionKind, Encounter, EncounterStoreKey, WoundLevel,
+};
+use crate::dice::feat::RollKind;
+
+type EncounterMap = HashMap<ChannelId, Encounter>;
+
+/// Get the shared encounter store
+async fn encounter_store(ctx: &Context) -> Option<Arc<RwLock<EncounterMap>>> {
+    ctx.data.read().await.get::<EncounterStoreKey>().cloned()
+}
+
+/// Handle the /combat command - start, populate, roll and step through an encounter
+pub async fn handle_combat(ctx: &Context, command: &CommandInteraction) {
+    let Some(subcommand) = command.data.options.first() else {
+        return;
+    };
+    let CommandDataOptionValue::SubCommand(options) = &subcommand.value else {
+        return;
+    };
+    let Some(store) = encounter_store(ctx).await else {
+        send_ephemeral(ctx, command, "⚠️ Combat tracking is not available").await;
+        return;
+    };
+    let channel_id = command.channel_id;
+
+    match subcommand.name.as_str() {
+        "start" => {
+            let running = {
+                let mut encounters = store.write().await;
+                let running = encounters.contains_key(&channel_id);
+                if !running {
+                    encounters.insert(channel_id, Encounter::new());
+                }
+                running
+            };
+            if running {
+                send_ephemeral(ctx, command, "⚠️ A combat is already running in this channel. Use `/combat end` first.").await;
+                return;
+            }
+            post_tracker(ctx, command, &store).await;
+        }
+        "show" => {
+            let running = store.read().await.contains_key(&channel_id);
+            if running {
+                post_tracker(ctx, command, &store).await;
+            } else {
+                send_ephemeral(ctx, command, NO_COMBAT).await;
+            }
+        }
+        "end" => {
+            let ended = store.write().await.remove(&channel_id);
+            let Some(encounter) = ended else {
+                send_ephemeral(ctx, command, NO_COMBAT).await;
+                return;
+            };
+            let summary = format!(
+                "🏁 **Combat ended** after {} round{}",
+                encounter.round,
+                if encounter.round == 1 { "" } else { "s" }
+            );
+            if let Some(message_id) = encounter.message_id {
+                let content = format!("{}\n━━━━━━━━━━━━━━\n{}", encounter.format_tracker(), summary);
+                if let Err(e) = channel_id
+                    .edit_message(&ctx.http, message_id, EditMessage::new().content(content))
+                    .await
+                {
+                    eprintln!("Error editing combat tracker: {:?}", e);
+                }
+            }
+            send_response(ctx, command, &summary).await;
+        }
+        name => {
+            // Read the sheet before locking the encounters
+            let character = if name == "join" {
+                find_character(ctx, command).await
+            } else {
+                None
+            };
+
+            let result = {
+                let mut encounters = store.write().await;
+                match encounters.get_mut(&channel_id) {
+                    None => Err(NO_COMBAT.to_string()),
+                    Some(encounter) => match name {
+                        "add" => add_combatant(encounter, options),
+                        "join" => join_combat(command, encounter, character, options),
+                        "roll" => {
+                            let mut dice = dice_source(command);
+                            encounter.roll_initiative(&mut dice);
+                            Ok(format!(
+                                "🎲 Initiative rolled for everyone\n-# 🌱 Seed: {}",
+                                dice.seed()
+                            ))
+                        }
+                        "next" => {
+                            encounter.next();
+                            Ok(match encounter.current() {
+                                Some(combatant) => format!("▶️ {} acts", combatant.name),
+                                None => format!(
+                                    "Round {}, Phase {}: {}",
+                                    encounter.round,
+                                    encounter.phase.number(),
+                                    encounter.phase.name()
+                                ),
+                            })
+                        }
+                        _ => return,
+                    },
+                }
+            };
+
+            match result {
+                Ok(ack) => {
+                    update_tracker(ctx, &store, channel_id).await;
+                    send_ephemeral(ctx, command, &ack).await;
+                }
+                Err(message) => send_ephemeral(ctx, command, &message).await,
+            }
+        }
+    }
+}
+
+const NO_COMBAT: &str = "⚠️ No combat running in this channel. Use `/combat start` first.";
+
+/// Post the tracker as the command response and remember it for later edits
+async fn post_tracker(ctx: &Context, command: &CommandInteraction, store: &RwLock<EncounterMap>) {
+    let tracker = store
+        .read()
+        .await
+        .get(&command.channel_id)
+        .map(|encounter| encounter.format_tracker());
+    let Some(content) = tracker else {
+        return;
+    };
+
+    send_response(ctx, command, &content).await;
+    match command.get_response(&ctx.http).await {
+        Ok(message) => remember_tracker(store, command.channel_id, message.id).await,
+        Err(e) => eprintln!("Error fetching combat tracker message: {:?}", e),
+    }
+}
+
+/// Edit the tracker message in place, posting a new one if it's gone
+///
+/// Reads the encounter from the store so no lock is held across the Discord calls.
+async fn update_tracker(ctx: &Context, store: &RwLock<EncounterMap>, channel_id: ChannelId) {
+    let tracker = store
+        .read()
+        .await
+        .get(&channel_id)
+        .map(|encounter| (encounter.format_tracker(), encounter.message_id));
+    let Some((content, message_id)) = tracker else {
+        return;
+    };
+
+    if let Some(message_id) = message_id {
+        let edit = EditMessage::new().content(content.clone());
+        match channel_id.edit_message(&ctx.http, message_id, edit).await {
+            Ok(_) => return,
+            Err(e) => eprintln!("Error editing combat tracker: {:?}", e),
+        }
+    }
+
+    match channel_id
+        .send_message(&ctx.http, CreateMessage::new().content(content))
+        .await
+    {
+        Ok(message) => remember_tracker(store, channel_id, message.id).await,
+        Err(e) => eprintln!("Error posting combat tracker: {:?}", e),
+    }
+}
+
+/// Remember the tracker message of a channel's encounter, if it's still running
+async fn remember_tracker(store: &RwLock<EncounterMap>, channel_id: ChannelId, message_id: MessageId) {
+    if let Some(encounter) = store.write().await.get_mut(&channel_id) {
+        encounter.message_id = Some(message_id);
+    }
+}
+
+fn add_combatant(encounter: &mut Encounter, options: &[CommandDataOption]) -> Result<String, String> {
+    let mut name = String::new();
+    let mut initiative_bonus: i32 = 0;
+    let mut owner = None;
+    let mut max_hits: Option<i32> = None;
+    let mut dm: i32 = 0;
+
+    for option in options {
+        match option.name.as_str() {
+            "name" => {
+                if let Some(val) = option.value.as_str() {
+                    name = val.trim().to_string();
+                }
+            }
+            "initiative_bonus" => {
+                if let Some(val) = option.value.as_i64() {
+                    initiative_bonus = val as i32;
+                }
+            }
+            "player" => owner = option.value.as_user_id(),
+            "hits" => {
+                if let Some(val) = option.value.as_i64() {
+                    max_hits = Some(val as i32);
+                }
+            }
+            "dm" => {
+                if let Some(val) = option.value.as_i64() {
+                    dm = val as i32;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut combatant = Combatant::new(&name, owner, initiative_bonus);
+    combatant.max_hits = max_hits;
+    combatant.defense.other = dm;
+    let kind = if combatant.is_npc() { "NPC" } else { "PC" };
+    encounter.add(combatant)?;
+    Ok(format!("✅ Added {} **{}** ({:+})", kind, name, initiative_bonus))
+}
+
+/// Condition penalties to a roll by a combatant in this channel's combat
+///
+/// Returns None when no combatant applies; naming one that isn't there is an error.
+pub async fn roll_conditions(
+    ctx: &Context,
+    command: &CommandInteraction,
+    name: Option<&str>,
+    kind: RollKind,
+) -> Result<Option<(String, RollPenalties)>, String> {
+    let combatant = acting_combatant(ctx, command, name).await?;
+    Ok(combatant.map(|c| (c.name.clone(), c.roll_penalties(kind))))
+}
+
+/// The combatant making a roll in this channel's combat
+///
+/// Uses the named combatant, or else the one the invoking user joined as.
+/// Returns None when no combatant applies; naming one that isn't there is an error.
+pub async fn acting_combatant(
+    ctx: &Context,
+    command: &CommandInteraction,
+    name: Option<&str>,
+) -> Result<Option<Combatant>, String> {
+    let store = encounter_store(ctx).await;
+    let encounters = match &store {
+        Some(store) => Some(store.read().await),
+        None => None,
+    };
+    let encounter = encounters
+        .as_ref()
+        .and_then(|encounters| encounters.get(&command.channel_id));
+
+    let combatant = match (name, encounter) {
+        (Some(name), Some(encounter)) => Some(
+            encounter
+                .find(name)
+                .ok_or_else(|| format!("⚠️ No combatant named **{}**", name))?,
+        ),
+        (Some(_), None) => return Err(NO_COMBAT.to_string()),
+        (None, Some(encounter)) => encounter
+            .combatants
+            .iter()
+            .find(|c| c.owner == Some(command.user.id)),
+        (None, None) => None,
+    };
+
+    Ok(combatant.cloned())
+}
+
+/// Record a combatant's Parry DM for the round and update the tracker
+pub async fn record_parry(ctx: &Context, command: &CommandInteraction, name: &str, dm: i32) {
+    let Some(store) = encounter_store(ctx).await else {
+        return;
+    };
+    {
+        let mut encounters = store.write().await;
+        let Some(combatant) = encounters
+            .get_mut(&command.channel_id)
+            .and_then(|encounter| encounter.find_mut(name))
+        else {
+            return;
+        };
+        combatant.parry = dm;
+    }
+    update_tracker(ctx, &store, command.channel_id).await;
+}
+
+/// Look up a combatant in this channel's combat by name
+pub async fn find_combatant(
+    ctx: &Context,
+    command: &CommandInteraction,
+    name: &str,
+) -> Result<Combatant, String> {
+    let store = encounter_store(ctx).await.ok_or(NO_COMBAT)?;
+    let encounters = store.read().await;
+    let encounter = encounters.get(&command.channel_id).ok_or(NO_COMBAT)?;
+    encounter
+        .find(name)
+        .cloned()
+        .ok_or_else(|| format!("⚠️ No combatant named **{}**", name))
+}
+
+/// Apply Hits to a combatant and update the tracker, returning the report
+pub async fn apply_hits(
+    ctx: &Context,
+    command: &CommandInteraction,
+    name: &str,
+    hits: i32,
+) -> Result<String, String> {
+    let store = encounter_store(ctx).await.ok_or(NO_COMBAT)?;
+    let report = {
+        let mut encounters = store.write().await;
+        let encounter = encounters.get_mut(&command.channel_id).ok_or(NO_COMBAT)?;
+        let combatant = encounter
+            .find_mut(name)
+            .ok_or_else(|| format!("⚠️ No combatant named **{}**", name))?;
+
+        let worsened = combatant.take_hits(hits);
+        format!(
+            "🩸 **{}** takes {} Hits ({}){}",
+            combatant.name,
+            hits,
+            combatant.format_hits(),
+            format_wound_change(&combatant.name, worsened)
+        )
+    };
+    update_tracker(ctx, &store, command.channel_id).await;
+    Ok(report)
+}
+
+/// Describe a combatant crossing a wound threshold, empty if they didn't
+fn format_wound_change(name: &str, worsened: Option<WoundLevel>) -> String {
+    match worsened {
+        Some(WoundLevel::Bruised) => format!(
+            "\n🩹 **{}** has lost half their Hits: Bruised (-4 to actions, not DM/Saves)",
+            name
+        ),
+        Some(WoundLevel::Wounded) => format!(
+            "\n🩹 **{}** has lost more than half their Hits: Bruised (-4 to actions, not DM/Saves), -10 Initiative",
+            name
+        ),
+        Some(WoundLevel::Incapacitated) => {
+            format!("\n😵 **{}** is Incapacitated at 0 Hits", name)
+        }
+        Some(WoundLevel::Dying) => format!(
+            "\n💀 **{}** is Dying below 0 Hits: add `/condition add kind:Dying` with the rounds left",
+            name
+        ),
+        _ => String::new(),
+    }
+}
+
+/// Handle the /hp command - damage, heal and set combatant Hits
+pub async fn handle_hp(ctx: &Context, command: &CommandInteraction) {
+    let Some(subcommand) = command.data.options.first() else {
+        return;
+    };
+    let CommandDataOptionValue::SubCommand(options) = &subcommand.value else {
+        return;
+    };
+    let Some(store) = encounter_store(ctx).await else {
+        send_ephemeral(ctx, command, "⚠️ Combat tracking is not available").await;
+        return;
+    };
+
+    let mut name = String::new();
+    let mut amount: i32 = 0;
+
+    for option in options {
+        match option.name.as_str() {
+            "combatant" => {
+                if let Some(val) = option.value.as_str() {
+                    name = val.trim().to_string();
+                }
+            }
+            "amount" => {
+                if let Some(val) = option.value.as_i64() {
+                    amount = val as i32;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let result = {
+        let mut encounters = store.write().await;
+        match encounters.get_mut(&command.channel_id) {
+            None => Err(NO_COMBAT.to_string()),
+            Some(encounter) if subcommand.name == "show" => Ok(format_hits_list(encounter, &name)),
+            Some(encounter) => match encounter.find_mut(&name) {
+                None => Err(format!("⚠️ No combatant named **{}**", name)),
+                Some(combatant) => Ok(match subcommand.name.as_str() {
+                    "damage" => {
+                        let worsened = combatant.take_hits(amount);
+                        format!(
+                            "🩸 **{}** takes {} Hits ({}){}",
+                            combatant.name,
+                            amount,
+                            combatant.format_hits(),
+                            format_wound_change(&combatant.name, worsened)
+                        )
+                    }
+                    "heal" => {
+                        combatant.heal(amount);
+                        format!("💚 **{}** heals {} Hits ({})", combatant.name, amount, combatant.format_hits())
+                    }
+                    "set" => {
+                        combatant.max_hits = Some(amount);
+                        format!("✏️ **{}**: {}", combatant.name, combatant.format_hits())
+                    }
+                    _ => return,
+                }),
+            },
+        }
+    };
+
+    match result {
+        Ok(content) => {
+            if subcommand.name != "show" {
+                update_tracker(ctx, &store, command.channel_id).await;
+            }
+            send_response(ctx, command, &content).await;
+        }
+        Err(message) => send_ephemeral(ctx, command, &message).await,
+    }
+}
+
+/// List Hits and wound levels, for one combatant or everyone if `name` is empty
+fn format_hits_list(encounter: &Encounter, name: &str) -> String {
+    let listed: Vec<String> = encounter
+        .combatants
+        .iter()
+        .filter(|c| name.is_empty() || c.name.eq_ignore_ascii_case(name))
+        .map(|c| {
+            let level = c.wound_level().map_or("max Hits not set", |l| l.name());
+            format!("**{}**: {} — {}", c.name, c.format_hits(), level)
+        })
+        .collect();
+    if listed.is_empty() {
+        format!("⚠️ No combatant named **{}**", name)
+    } else {
+        format!("❤️ **Hits**\n━━━━━━━━━━━━━━\n{}", listed.join("\n"))
+    }
+}
+
+/// Format a combatant's condition penalties for a roll, empty if there are none
+pub fn format_roll_conditions(name: &str, penalties: &RollPenalties) -> String {
+    let mut output = String::new();
+    if !penalties.items.is_empty() {
+        output.push_str(&format!("\nConditions ({}): {}", name, penalties.breakdown()));
+    }
+    for restriction in &penalties.restrictions {
+        output.push_str(&format!("\n⛔ {}", restriction));
+    }
+    output
+}
+
+/// Add the invoking user's character, using the sheet's name and Dexterity
+fn join_combat(
+    command: &CommandInteraction,
+    encounter: &mut Encounter,
+    character: Option<Character>,
+    options: &[CommandDataOption],
+) -> Result<String, String> {
+    let user_id = command.user.id;
+    if encounter.combatants.iter().any(|c| c.owner == Some(user_id)) {
+        return Err("⚠️ You have already joined this combat".to_string());
+    }
+
+    let initiative_bonus = options
+        .iter()
+        .find(|opt| opt.name == "initiative_bonus")
+        .and_then(|opt| opt.value.as_i64())
+        .map(|v| v as i32)
+        .or(character.as_ref().map(|c| c.dexterity()))
+        .unwrap_or(0);
+    let max_hits = character.as_ref().map(|c| c.hits).filter(|&hits| hits > 0);
+    let defense = character.as_ref().map(|c| c.defense()).unwrap_or_default();
+    let name = character
+        .map(|c| c.name)
+        .unwrap_or_else(|| command.user.display_name().to_string());
+
+    let mut combatant = Combatant::new(&name, Some(user_id), initiative_bonus);
+    combatant.max_hits = max_hits;
+    combatant.defense = defense;
+    encounter.add(combatant)?;
+    Ok(format!("✅ **{}** joins the combat ({:+})", name, initiative_bonus))
+}
+
+/// Handle the /condition command - add, remove and list combatant conditions
+pub async fn handle_condition(ctx: &Context, command: &CommandInteraction) {
+    let Some(subcommand) = command.data.options.first() else {
+        return;
+    };
+    let CommandDataOptionValue::SubCommand(options) = &subcommand.value else {
+        return;
+    };
+    let Some(store) = encounter_store(ctx).await else {
+        send_ephemeral(ctx, command, "⚠️ Combat tracking is not available").await;
+        return;
+    };
+    let mut name = String::new();
+    let mut kind: Option<ConditionKind> = None;
+    let mut value: u8 = 1;
+    let mut rounds: Option<u8> = None;
+
+    for option in options {
+        match option.name.as_str() {
+            "combatant" => {
+                if let Some(val) = option.value.as_str() {
+                    name = val.trim().to_string();
+                }
+            }
+            "kind" => kind = option.value.as_str().and_then(ConditionKind::from_choice),
+            "value" => {
+                if let Some(val) = option.value.as_i64() {
+                    value = val as u8;
+                }
+            }
+            "rounds" => {
+                if let Some(val) = option.value.as_i64() {
+                    rounds = Some(val as u8);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let result = {
+        let mut encounters = store.write().await;
+        match encounters.get_mut(&command.channel_id) {
+            None => Err(NO_COMBAT.to_string()),
+            Some(encounter) if subcommand.name == "list" => {
+                Ok(format_conditions_list(encounter, &name))
+            }
+            Some(encounter) => match (encounter.find_mut(&name), kind) {
+                (None, _) => Err(format!("⚠️ No combatant named **{}**", name)),
+                (Some(_), None) => return,
+                (Some(combatant), Some(kind)) => match subcommand.name.as_str() {
+                    "add" => {
+                        let condition = Condition::new(kind, value, rounds);
+                        combatant.add_condition(condition);
+                        Ok(format!("✅ **{}**: {} — {}", combatant.name, condition, kind.effect()))
+                    }
+                    "remove" if combatant.remove_condition(kind) => {
+                        Ok(format!("✅ **{}** is no longer {}", combatant.name, kind.name()))
+                    }
+                    "remove" => Err(format!("⚠️ **{}** isn't {}", combatant.name, kind.name())),
+                    _ => return,
+                },
+            },
+        }
+    };
+
+    match result {
+        Ok(content) if subcommand.name == "list" => send_response(ctx, command, &content).await,
+        Ok(ack) => {
+            update_tracker(ctx, &store, command.channel_id).await;
+            send_ephemeral(ctx, command, &ack).await;
+        }
+        Err(message) => send_ephemeral(ctx, command, &message).await,
+    }
+}
+
+/// List conditions, for one combatant or everyone if `name` is empty
+fn format_conditions_list(encounter: &Encounter, name: &str) -> String {
+    let listed: Vec<String> = encounter
+        .combatants
+        .iter()
+        .filter(|c| name.is_empty() || c.name.eq_ignore_ascii_case(name))
+        .map(|c| c.format_conditions())
+        .collect();
+    if listed.is_empty() {
+        format!("⚠️ No combatant named **{}**", name)
+    } else {
+        format!("🩹 **Conditions**\n━━━━━━━━━━━━━━\n{}", listed.join("\n\n"))
+    }
+}
+
+/// Handle the /upkeep command - Phase 1 bleeding and condition durations
+pub async fn handle_upkeep(ctx: &Context, command: &CommandInteraction) {
+    let Some(store) = encounter_store(ctx).await else {
+        send_ephemeral(ctx, command, "⚠️ Combat tracking is not available").await;
+        return;
+    };
+    let result = {
+        let mut encounters = store.write().await;
+        match encounters.get_mut(&command.channel_id) {
+            None => Err(NO_COMBAT.to_string()),
+            Some(encounter) => run_upkeep(encounter),
+        }
+    };
+
+    match result {
+        Ok(content) => {
+            update_tracker(ctx, &store, command.channel_id).await;
+            send_response(ctx, command, &content).await;
+        }
+        Err(message) => send_ephemeral(ctx, command, &message).await,
+    }
+}
+
+/// Apply upkeep to an encounter and describe what happened
+fn run_upkeep(encounter: &mut Encounter) -> Result<String, String> {
+    let mut lines = Vec::new();
+    for (name, result, worsened) in encounter.upkeep()? {
+        if result.bleeding > 0 {
+            lines.push(format!(
+                "🩸 **{}** loses {} Hits (Bleed){}",
+                name,
+                result.bleeding,
+                format_wound_change(&name, worsened)
+            ));
+        }
+        for kind in result.expired {
+            if kind == ConditionKind::Dying {
+                lines.push(format!("💀 **{}** ran out of time: dies unless already healed", name));
+            } else {
+                lines.push(format!("⏱️ **{}** is no longer {}", name, kind.name()));
+            }
+        }
+    }
+    for combatant in &encounter.combatants {
+        if let Some(summary) = combatant.penalty_summary() {
+            lines.push(format!("⚠️ **{}**: {}", combatant.name, summary));
+        }
+    }
+    if lines.is_empty() {
+        lines.push(String::from("Nothing to apply this round."));
+    }
+
+    Ok(format!(
+        "🩺 **Upkeep — Round {}**\n━━━━━━━━━━━━━━\n{}",
+        encounter.round,
+        lines.join("\n")
+    ))
+}
+
+/// Register the /combat command
+pub fn register_combat() -> CreateCommand {
+    let initiative_bonus = || {
+        CreateCommandOption::new(
+            CommandOptionType::Integer,
+            "initiative_bonus",
+            "Dexterity + Initiative modifiers",
+        )
+        .required(false)
+    };
+
+    CreateCommand::new("combat")
+        .description("Track a combat encounter and initiative order in this channel")
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "start",
+            "Start a combat encounter in this channel",
+        ))
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "add",
+                "Add an NPC (or a PC for another player)",
+            )
+            .add_sub_option(
+                CreateCommandOption::new(CommandOptionType::String, "name", "Combatant name")
+                    .required(true)
+                    .max_length(64),
+            )
+            .add_sub_option(initiative_bonus())
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::User,
+                    "player",
+                    "Player controlling this combatant (leave empty for NPCs)",
+                )
+                .required(false),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(CommandOptionType::Integer, "hits", "Maximum Hits")
+                    .required(false)
+                    .min_int_value(1)
+                    .max_int_value(500),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "dm",
+                    "Defensive Modifier, used when they're the target of /attack",
+                )
+                .required(false)
+                .max_int_value(100),
+            ),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "join",
+                "Join with your character (name, Dexterity, Hits and DM from your sheet)",
+            )
+            .add_sub_option(initiative_bonus()),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "roll",
+                "Roll Initiative for everyone (2d10, NOT open-ended)",
+            )
+            .add_sub_option(seed_option()),
+        )
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "next",
+            "Advance to the next phase or the next combatant's action",
+        ))
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "show",
+            "Post the tracker again at the bottom of the channel",
+        ))
+        .add_option(CreateCommandOption::new(
+            CommandOptionType::SubCommand,
+            "end",
+            "End the combat in this channel",
+        ))
+}
+
+/// Register the /condition command
+pub fn register_condition() -> CreateCommand {
+    let combatant = |required: bool| {
+        CreateCommandOption::new(CommandOptionType::String, "combatant", "Combatant name")
+            .required(required)
+            .max_length(64)
+    };
+    let kind = || {
+        ConditionKind::ALL.into_iter().fold(
+            CreateCommandOption::new(CommandOptionType::String, "kind", "Condition").required(true),
+            |option, kind| option.add_string_choice(kind.name(), kind.choice()),
+        )
+    };
+
+    CreateCommand::new("condition")
+        .description("Track conditions and critical effects on combatants")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "add",
+                "Add a condition (stacks with the same kind)",
+            )
+            .add_sub_option(combatant(true))
+            .add_sub_option(kind())
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "value",
+                    "The # in Bleed(#)/Pain(#), or rounds for Dazed/Stunned/Dying (default 1)",
+                )
+                .required(false)
+                .min_int_value(1)
+                .max_int_value(50),
+            )
+            .add_sub_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "rounds",
+                    "How many rounds it lasts (default: until removed)",
+                )
+                .required(false)
+                .min_int_value(1)
+                .max_int_value(100),
+            ),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "remove",
+                "Remove a condition",
+            )
+            .add_sub_option(combatant(true))
+            .add_sub_option(kind()),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "list",
+                "List conditions and the penalties to add to rolls",
+            )
+            .add_sub_option(combatant(false)),
+        )
+}
+
+/// Register the /hp command
+pub fn register_hp() -> CreateCommand {
+    let combatant = |required: bool| {
+        CreateCommandOption::new(CommandOptionType::String, "combatant", "Combatant name")
+            .required(required)
+            .max_length(64)
+    };
+    let amount = |description: &str| {
+        CreateCommandOption::new(CommandOptionType::Integer, "amount", description)
+            .required(true)
+            .min_int_value(0)
+            .max_int_value(500)
+    };
+
+    CreateCommand::new("hp")
+        .description("Track combatant Hits and wounds")
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::SubCommand, "damage", "Take Hits")
+                .add_sub_option(combatant(true))
+                .add_sub_option(amount("Hits lost")),
+        )
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::SubCommand, "heal", "Heal Hits")
+                .add_sub_option(combatant(true))
+                .add_sub_option(amount("Hits healed")),
+        )
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::SubCommand, "set", "Set maximum Hits")
+                .add_sub_option(combatant(true))
+                .add_sub_option(amount("Maximum Hits")),
+        )
+        .add_option(
+            CreateCommandOption::new(CommandOptionType::SubCommand, "show", "Show Hits and wounds")
+                .add_sub_option(combatant(false)),
+        )
+}
+
+/// Register the /upkeep command
+pub fn register_upkeep() -> CreateCommand {
+    CreateCommand::new("upkeep")
+        .description("Phase 1 Upkeep: apply bleeding, tick down Stun/Dazed and expire conditions")
+}
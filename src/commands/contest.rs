@@ -0,0 +1,252 @@
+//! /contest and /opposed commands - conflicting actions between named participants
+//!
+//! When first place is tied the result gets a re-roll button. The tied
+//! participants are kept in memory by message until the tie is broken or
+//! expires.
+
+use std::sync::Arc;
+
+use serenity::all::{
+    ButtonStyle, CommandInteraction, CommandOptionType, ComponentInteraction, Context,
+    CreateActionRow, CreateButton, CreateCommand, CreateCommandOption, CreateInteractionResponse,
+    CreateInteractionResponseMessage, MessageId,
+};
+use serenity::prelude::{RwLock, TypeMapKey};
+
+use super::message_store::MessageStore;
+use super::{dice_source, seed_option, send_component_ephemeral, send_ephemeral};
+use crate::dice::contest::{
+    format_contest, is_tied, leaders, parse_contestants, roll_contest, Contestant,
+};
+use crate::dice::SeededSource;
+
+/// Custom ID of the tie re-roll button
+pub const REROLL_BUTTON_ID: &str = "contest_reroll";
+
+/// Discord's message length limit
+const MESSAGE_LIMIT: usize = 2000;
+
+/// Participants tied for first, by the message showing the tie
+pub struct ContestStoreKey;
+
+impl TypeMapKey for ContestStoreKey {
+    type Value = Arc<RwLock<MessageStore<Vec<Contestant>>>>;
+}
+
+/// Handle the /contest command - any number of named participants
+pub async fn handle_contest(ctx: &Context, command: &CommandInteraction) {
+    let mut participants = "";
+
+    for option in &command.data.options {
+        if option.name == "participants" {
+            if let Some(val) = option.value.as_str() {
+                participants = val;
+            }
+        }
+    }
+
+    let contestants = match parse_contestants(participants) {
+        Ok(contestants) => contestants,
+        Err(message) => {
+            send_ephemeral(ctx, command, &message).await;
+            return;
+        }
+    };
+
+    run_contest(ctx, command, "⚔️ **Contest**", &contestants).await;
+}
+
+/// Handle the /opposed command - opposed roll between two participants
+pub async fn handle_opposed(ctx: &Context, command: &CommandInteraction) {
+    let mut first = Contestant {
+        name: "Participant 1".to_string(),
+        bonus: 0,
+    };
+    let mut second = Contestant {
+        name: "Participant 2".to_string(),
+        bonus: 0,
+    };
+
+    for option in &command.data.options {
+        match option.name.as_str() {
+            "bonus1" => {
+                if let Some(val) = option.value.as_i64() {
+                    first.bonus = val as i32;
+                }
+            }
+            "bonus2" => {
+                if let Some(val) = option.value.as_i64() {
+                    second.bonus = val as i32;
+                }
+            }
+            "name1" => {
+                if let Some(val) = option.value.as_str() {
+                    first.name = val.to_string();
+                }
+            }
+            "name2" => {
+                if let Some(val) = option.value.as_str() {
+                    second.name = val.to_string();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    run_contest(ctx, command, "⚔️ **Opposed Roll**", &[first, second]).await;
+}
+
+/// Roll a contest, post the ranking and offer a re-roll if first place is tied
+async fn run_contest(
+    ctx: &Context,
+    command: &CommandInteraction,
+    title: &str,
+    contestants: &[Contestant],
+) {
+    let mut dice = dice_source(command);
+    let ranked = roll_contest(&mut dice, contestants);
+    let tied = is_tied(&ranked);
+
+    let content = format!(
+        "{}\n━━━━━━━━━━━━━━\n{}\n-# 🌱 Seed: {}",
+        title,
+        format_contest(&ranked),
+        dice.seed()
+    );
+    let mut message = CreateInteractionResponseMessage::new().content(content);
+    if tied {
+        message = message.components(vec![reroll_button()]);
+    }
+
+    if let Err(e) = command
+        .create_response(&ctx.http, CreateInteractionResponse::Message(message))
+        .await
+    {
+        eprintln!("Error sending response: {:?}", e);
+        return;
+    }
+
+    if tied {
+        match command.get_response(&ctx.http).await {
+            Ok(response) => {
+                let tied = leaders(&ranked).iter().map(|roll| roll.contestant.clone()).collect();
+                remember_tie(ctx, response.id, tied).await;
+            }
+            Err(e) => eprintln!("Error fetching contest response: {:?}", e),
+        }
+    }
+}
+
+/// Handle the tie re-roll button - re-roll only the participants tied for first
+pub async fn handle_contest_reroll(ctx: &Context, component: &ComponentInteraction) {
+    let Some(store) = contest_store(ctx).await else {
+        return;
+    };
+    // Taking the entry out means a second click can't re-roll the same tie
+    let Some(contestants) = store.write().await.remove(&component.message.id) else {
+        send_component_ephemeral(ctx, component, "⚠️ This tie has already been re-rolled or has expired")
+            .await;
+        return;
+    };
+
+    let mut dice = SeededSource::from_entropy();
+    let ranked = roll_contest(&mut dice, &contestants);
+    let tied = is_tied(&ranked);
+
+    let reroll = format!(
+        "🔁 **Tie re-roll**\n━━━━━━━━━━━━━━\n{}\n-# 🌱 Seed: {}",
+        format_contest(&ranked),
+        dice.seed()
+    );
+    let mut content = format!("{}\n\n{}", component.message.content, reroll);
+    if content.chars().count() > MESSAGE_LIMIT {
+        content = reroll;
+    }
+
+    let mut message = CreateInteractionResponseMessage::new().content(content);
+    message = if tied {
+        message.components(vec![reroll_button()])
+    } else {
+        message.components(vec![])
+    };
+
+    if let Err(e) = component
+        .create_response(&ctx.http, CreateInteractionResponse::UpdateMessage(message))
+        .await
+    {
+        eprintln!("Error updating contest: {:?}", e);
+        return;
+    }
+
+    if tied {
+        let tied = leaders(&ranked).iter().map(|roll| roll.contestant.clone()).collect();
+        remember_tie(ctx, component.message.id, tied).await;
+    }
+}
+
+/// Get the shared store of unresolved ties
+async fn contest_store(ctx: &Context) -> Option<Arc<RwLock<MessageStore<Vec<Contestant>>>>> {
+    ctx.data.read().await.get::<ContestStoreKey>().cloned()
+}
+
+/// Keep the tied participants so the re-roll button can pick them up
+async fn remember_tie(ctx: &Context, message_id: MessageId, contestants: Vec<Contestant>) {
+    if let Some(store) = contest_store(ctx).await {
+        store.write().await.insert(message_id, contestants);
+    }
+}
+
+/// Action row holding the tie re-roll button
+fn reroll_button() -> CreateActionRow {
+    CreateActionRow::Buttons(vec![CreateButton::new(REROLL_BUTTON_ID)
+        .label("Re-roll the tie")
+        .emoji('🔁')
+        .style(ButtonStyle::Primary)])
+}
+
+/// Register the /contest command
+pub fn register_contest() -> CreateCommand {
+    CreateCommand::new("contest")
+        .description("Conflicting actions: any number of participants roll, highest wins")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::String,
+                "participants",
+                "Participants and their Skill bonuses, e.g. Thief:+12, Guard:+8, Dog:+10",
+            )
+            .required(true)
+            .max_length(1000),
+        )
+        .add_option(seed_option())
+}
+
+/// Register the /opposed command
+pub fn register_opposed() -> CreateCommand {
+    let name = |name: &str, description: &str| {
+        CreateCommandOption::new(CommandOptionType::String, name, description)
+            .required(false)
+            .max_length(64)
+    };
+
+    CreateCommand::new("opposed")
+        .description("Make an opposed roll between two participants")
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "bonus1",
+                "Participant 1's bonus",
+            )
+            .required(true),
+        )
+        .add_option(
+            CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "bonus2",
+                "Participant 2's bonus",
+            )
+            .required(true),
+        )
+        .add_option(name("name1", "Participant 1's name"))
+        .add_option(name("name2", "Participant 2's name"))
+        .add_option(seed_option())
+}
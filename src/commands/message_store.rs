@@ -0,0 +1,54 @@
+//! Interactive state kept in memory by message
+//!
+//! Buttons and menus that need to remember something between clicks keep it
+//! here, by the message holding them. Discord doesn't expire these components
+//! (each click brings its own interaction token); the 15 minute lifetime is
+//! this bot's own cleanup policy, so ignored menus and ties don't pile up while
+//! it runs. Raise it if players need longer to decide.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use serenity::all::MessageId;
+
+/// How long an entry stays usable after it's stored
+pub const ENTRY_LIFETIME: Duration = Duration::from_secs(15 * 60);
+
+/// Values by the message holding their buttons or menu, expiring over time
+pub struct MessageStore<T> {
+    entries: HashMap<MessageId, (Instant, T)>,
+}
+
+impl<T> Default for MessageStore<T> {
+    fn default() -> Self {
+        Self {
+            entries: HashMap::new(),
+        }
+    }
+}
+
+impl<T> MessageStore<T> {
+    /// Keep a value for a message, dropping any entries that have expired
+    pub fn insert(&mut self, message_id: MessageId, value: T) {
+        let now = Instant::now();
+        self.entries
+            .retain(|_, (stored, _)| now.duration_since(*stored) < ENTRY_LIFETIME);
+        self.entries.insert(message_id, (now, value));
+    }
+
+    /// The value for a message, unless it has expired
+    pub fn get_mut(&mut self, message_id: &MessageId) -> Option<&mut T> {
+        self.entries
+            .get_mut(message_id)
+            .filter(|(stored, _)| stored.elapsed() < ENTRY_LIFETIME)
+            .map(|(_, value)| value)
+    }
+
+    /// Take the value for a message out, unless it has expired
+    pub fn remove(&mut self, message_id: &MessageId) -> Option<T> {
+        self.entries
+            .remove(message_id)
+            .filter(|(stored, _)| stored.elapsed() < ENTRY_LIFETIME)
+            .map(|(_, value)| value)
+    }
+}
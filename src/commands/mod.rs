@@ -1,13 +1,69 @@
 //! Discord slash commands for Fantasy Express dice roller
 
+mod boons;
+mod casting;
+mod character;
+mod combat;
+mod contest;
+mod message_store;
+
+pub use boons::{handle_boon_component, BoonStoreKey, BOON_DONE_ID, BOON_MENU_ID};
+pub use casting::{handle_partial_cast, PartialCasting};
+pub use character::handle_char;
+pub use combat::{handle_combat, handle_condition, handle_hp, handle_upkeep};
+pub use contest::{
+    handle_contest, handle_contest_reroll, handle_opposed, ContestStoreKey, REROLL_BUTTON_ID,
+};
+
 use serenity::all::{
-    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
-    CreateInteractionResponse, CreateInteractionResponseMessage,
+    CommandDataOptionValue, CommandInteraction, CommandOptionType, ComponentInteraction, Context, CreateCommand, CreateCommandOption,
+    CreateInteractionResponse, CreateInteractionResponseFollowup,
+    CreateInteractionResponseMessage,
 };
 
-use crate::dice::{resolve_feat, roll_2d10_closed, roll_2d10_open, roll_with_modifier};
+use crate::combat::{ConditionKind, WoundLevel};
+use crate::dice::{
+    resolve_feat, roll_2d10_closed, roll_2d10_open, roll_with_modifier, SeededSource,
+    DEFAULT_FUMBLE_RANGE,
+};
+use crate::dice::resonance::{
+    format_resonance_result, resolve_resonance, triggers_resonance, CastingLocation,
+    ResonanceModifiers, SpellNature,
+};
 use crate::dice::roll::format_roll;
-use crate::dice::feat::format_feat_result;
+use crate::dice::asf::{
+    fumble_range as asf_fumble_range, spell_fumble_modifier, AsfSources, ComponentUse,
+    SpellOrigin,
+};
+use crate::dice::contest::{roll_contestant, ContestRoll, Contestant};
+use crate::dice::criticals::{format_criticals, DamageType};
+use crate::dice::damage::{format_damage, resolve_damage, ArmorPiercing};
+use crate::dice::defense::{prone_modifier, Cover, DefenseModifiers};
+use crate::dice::difficulty::{format_difficulty_table, Difficulty, TAKING_THE_TIME_BONUS};
+use crate::dice::feat::{format_feat_result, FeatResult, RollKind};
+use crate::dice::fumble::{
+    resolve_weapon_fumble, weapon_category, WeaponFumbleModifiers, WEAPON_FUMBLE_CATEGORIES,
+};
+use crate::dice::maneuver::{
+    judge, maneuver_effect, shield_bash_damage_rating, Maneuver, ManeuverResult, ManeuverSetup,
+    RiposteOpening,
+};
+use crate::dice::helping::{format_help, parse_helpers, roll_helpers, total_help};
+use crate::dice::initiative::{Encumbrance, InitiativeModifiers, ShieldSize};
+use crate::dice::odds::{feat_odds, format_feat_odds, DEFAULT_EXPLOSION_DEPTH};
+use crate::dice::parry::{allocate_parry, ParryFoes, ParryOptions};
+use crate::dice::range::{Aim, RangedModifiers};
+use crate::dice::save::{format_save_outcome, roll_save, SaveType};
+use crate::dice::spell_attack::{
+    bolt_range_modifier, cone_width, parse_targets, resolve_targets, SpellShape,
+};
+use boons::send_roll_with_boons;
+use casting::send_partial_cast;
+use character::find_character;
+use combat::{
+    acting_combatant, apply_hits, find_combatant, format_roll_conditions, record_parry,
+    roll_conditions,
+};
 
 /// Handle the /roll command - basic 2d10 open-ended roll with optional modifier
 pub async fn handle_roll(ctx: &Context, command: &CommandInteraction) {
@@ -21,7 +77,8 @@ pub async fn handle_roll(ctx: &Context, command: &CommandInteraction) {
         .map(|v| v as i32)
         .unwrap_or(0);
 
-    let result = roll_2d10_open();
+    let mut dice = dice_source(command);
+    let result = roll_2d10_open(&mut dice);
     let final_total = result.raw_total + modifier;
     let formatted = format_roll(&result, if modifier != 0 { Some(modifier) } else { None });
 
@@ -37,118 +94,501 @@ pub async fn handle_roll(ctx: &Context, command: &CommandInteraction) {
         )
     };
 
-    send_response(ctx, command, &response).await;
+    send_roll_response(ctx, command, &response, &dice).await;
 }
 
 /// Handle the /skill command - skill check with bonus and difficulty
 pub async fn handle_skill(ctx: &Context, command: &CommandInteraction) {
-    let mut bonus: i32 = 0;
-    let mut difficulty: i32 = 0;
+    let mut bonus: Option<i32> = None;
+    let mut skill: Option<String> = None;
+    let mut difficulty = Difficulty::default();
+    let mut take_time = false;
+    let mut modifier: i32 = 0;
+    let mut combatant: Option<String> = None;
+    let mut helpers: Option<&str> = None;
 
     for option in &command.data.options {
         match option.name.as_str() {
             "bonus" => {
                 if let Some(val) = option.value.as_i64() {
-                    bonus = val as i32;
+                    bonus = Some(val as i32);
+                }
+            }
+            "helpers" => {
+                helpers = option.value.as_str();
+            }
+            "skill" => {
+                if let Some(val) = option.value.as_str() {
+                    skill = Some(val.trim().to_string());
                 }
             }
             "difficulty" => {
+                if let Some(val) = option.value.as_str().and_then(Difficulty::from_choice) {
+                    difficulty = val;
+                }
+            }
+            "take_time" => {
+                if let Some(val) = option.value.as_bool() {
+                    take_time = val;
+                }
+            }
+            "modifier" => {
                 if let Some(val) = option.value.as_i64() {
-                    difficulty = val as i32;
+                    modifier = val as i32;
+                }
+            }
+            "combatant" => {
+                if let Some(val) = option.value.as_str() {
+                    combatant = Some(val.trim().to_string());
                 }
             }
             _ => {}
         }
     }
 
-    let total_modifier = bonus + difficulty;
-    let (result, final_total) = roll_with_modifier(total_modifier);
+    // An explicit bonus wins; otherwise pull the named skill from the sheet
+    let (skill_label, bonus) = match (bonus, skill) {
+        (Some(bonus), skill) => (skill, bonus),
+        (None, Some(skill)) => {
+            let found = find_character(ctx, command).await.and_then(|character| {
+                character
+                    .skill(&skill)
+                    .map(|(name, bonus)| (name.to_string(), bonus))
+            });
+            match found {
+                Some((name, bonus)) => (Some(name), bonus),
+                None => {
+                    let message = format!(
+                        "⚠️ No skill named **{}** on your sheet. Add it with `/char set field:skill` or give a `bonus`.",
+                        skill
+                    );
+                    send_ephemeral(ctx, command, &message).await;
+                    return;
+                }
+            }
+        }
+        (None, None) => {
+            send_ephemeral(ctx, command, "⚠️ Give a `bonus` or a `skill` from your sheet").await;
+            return;
+        }
+    };
+
+    let Some((condition_penalty, conditions_str)) =
+        condition_penalty(ctx, command, combatant.as_deref(), RollKind::Skill).await
+    else {
+        return;
+    };
+
+    let helpers = match helpers.map(parse_helpers).transpose() {
+        Ok(helpers) => helpers.unwrap_or_default(),
+        Err(message) => {
+            send_ephemeral(ctx, command, &message).await;
+            return;
+        }
+    };
+
+    // Helpers roll first, from the same dice, and their bonuses feed the lead's roll
+    let mut dice = dice_source(command);
+    let help_rolls = roll_helpers(&mut dice, &helpers);
+    let help = total_help(&help_rolls);
+    let (help_chain, help_str) = if help_rolls.is_empty() {
+        (String::new(), String::new())
+    } else {
+        (format!("{}\n━━━━━━━━━━━━━━\n", format_help(&help_rolls)), format!(" | Help: {:+}", help))
+    };
+
+    let take_time_bonus = if take_time { TAKING_THE_TIME_BONUS } else { 0 };
+    let total_modifier = bonus + difficulty.modifier() + take_time_bonus + modifier + condition_penalty + help;
+    let (result, final_total) = roll_with_modifier(&mut dice, total_modifier, DEFAULT_FUMBLE_RANGE);
     let feat_result = resolve_feat(final_total, result.is_fumble);
 
     let formatted_roll = format_roll(&result, Some(total_modifier));
-    let formatted_feat = format_feat_result(&feat_result, final_total);
+    let formatted_feat = format_feat_result(&feat_result, RollKind::Skill, final_total);
 
-    let difficulty_str = if difficulty >= 0 {
-        format!("+{}", difficulty)
-    } else {
-        format!("{}", difficulty)
+    let mut difficulty_str = format!("{} {:+}", difficulty.name(), difficulty.modifier());
+    if take_time {
+        difficulty_str.push_str(&format!(" | Taking the Time {:+} (double time)", take_time_bonus));
+    }
+    if modifier != 0 {
+        difficulty_str.push_str(&format!(" | Other {:+}", modifier));
+    }
+
+    let title = match &skill_label {
+        Some(skill) => format!("{} Roll", skill),
+        None => String::from("Skill Roll"),
     };
 
     let response = format!(
-        "🎲 **Skill Roll**\n━━━━━━━━━━━━━━\nDice: {}\nBonus: {:+} | Difficulty: {}\n━━━━━━━━━━━━━━\n{}",
-        formatted_roll, bonus, difficulty_str, formatted_feat
+        "🎲 **{}**\n━━━━━━━━━━━━━━\n{}Dice: {}\nBonus: {:+} | Difficulty: {}{}{}\n━━━━━━━━━━━━━━\n{}",
+        title, help_chain, formatted_roll, bonus, difficulty_str, help_str, conditions_str, formatted_feat
     );
 
-    send_response(ctx, command, &response).await;
+    send_roll_with_boons(ctx, command, &response, &dice, RollKind::Skill, feat_result.success_levels()).await;
 }
 
 /// Handle the /initiative command - 2d10 (NOT open-ended) + Dex + modifiers
 pub async fn handle_initiative(ctx: &Context, command: &CommandInteraction) {
-    let mut dexterity: i32 = 0;
-    let mut modifier: i32 = 0;
+    let mut dexterity: Option<i32> = None;
+    let mut modifiers = InitiativeModifiers::default();
 
     for option in &command.data.options {
         match option.name.as_str() {
             "dexterity" => {
                 if let Some(val) = option.value.as_i64() {
-                    dexterity = val as i32;
+                    dexterity = Some(val as i32);
                 }
             }
-            "modifier" => {
+            "weapon_not_ready" => {
+                if let Some(val) = option.value.as_bool() {
+                    modifiers.weapon_not_ready = val;
+                }
+            }
+            "surprised" => {
+                if let Some(val) = option.value.as_bool() {
+                    modifiers.surprised = val;
+                }
+            }
+            "two_weapons" => {
+                if let Some(val) = option.value.as_bool() {
+                    modifiers.two_weapons = val;
+                }
+            }
+            "heavy_weapon" => {
+                if let Some(val) = option.value.as_bool() {
+                    modifiers.heavy_weapon = val;
+                }
+            }
+            "shield" => {
+                if let Some(val) = option.value.as_str().and_then(ShieldSize::from_choice) {
+                    modifiers.shield = val;
+                }
+            }
+            "encumbrance" => {
+                if let Some(val) = option.value.as_str().and_then(Encumbrance::from_choice) {
+                    modifiers.encumbrance = val;
+                }
+            }
+            "wounded" => {
+                if let Some(val) = option.value.as_bool() {
+                    modifiers.wounded = val;
+                }
+            }
+            "spell_tier" => {
                 if let Some(val) = option.value.as_i64() {
-                    modifier = val as i32;
+                    modifiers.spell_tier = val as i32;
+                }
+            }
+            "weapon_length_steps" => {
+                if let Some(val) = option.value.as_i64() {
+                    modifiers.weapon_length_steps = val as i32;
+                }
+            }
+            "closing" => {
+                if let Some(val) = option.value.as_bool() {
+                    modifiers.closing = val;
                 }
             }
             _ => {}
         }
     }
 
-    let (d1, d2, dice_total) = roll_2d10_closed();
+    let dexterity = match dexterity {
+        Some(dexterity) => dexterity,
+        None => match find_character(ctx, command).await {
+            Some(character) => character.dexterity(),
+            None => {
+                send_ephemeral(ctx, command, "⚠️ Give your `dexterity` or create a sheet with `/char create`").await;
+                return;
+            }
+        },
+    };
+
+    // Apply the wounded penalty when the tracker says you've lost more than half your Hits
+    if !modifiers.wounded {
+        if let Ok(Some(combatant)) = acting_combatant(ctx, command, None).await {
+            modifiers.wounded = combatant.wound_level() >= Some(WoundLevel::Wounded);
+        }
+    }
+
+    let mut dice = dice_source(command);
+    let (d1, d2, dice_total) = roll_2d10_closed(&mut dice);
+    let modifier = modifiers.total();
     let final_total = dice_total + dexterity + modifier;
 
-    let modifier_str = if modifier != 0 {
-        format!(" {:+}", modifier)
-    } else {
-        String::new()
-    };
+    let modifier_str: String = modifiers
+        .itemize()
+        .iter()
+        .map(|(name, value)| format!("\n• {}: {:+}", name, value))
+        .collect();
 
     let response = format!(
         "⚔️ **Initiative**\n━━━━━━━━━━━━━━\nDice: [{}, {}] = {}\nDexterity: {:+}{}\n━━━━━━━━━━━━━━\n**Initiative: {}**",
         d1, d2, dice_total, dexterity, modifier_str, final_total
     );
 
-    send_response(ctx, command, &response).await;
+    send_roll_response(ctx, command, &response, &dice).await;
 }
 
 /// Handle the /attack command - attack roll with FEAT resolution
 pub async fn handle_attack(ctx: &Context, command: &CommandInteraction) {
-    let mut attack_bonus: i32 = 0;
-    let mut defense: i32 = 0;
-    let mut damage_rating: i32 = 0;
+    let mut attack_bonus: Option<i32> = None;
+    let mut weapon: Option<String> = None;
+    let mut defense: Option<i32> = None;
+    let mut cover = Cover::None;
+    let mut damage_rating: Option<i32> = None;
+    let mut damage_type: Option<DamageType> = None;
+    let mut armor_rating: i32 = 0;
+    let mut piercing = ArmorPiercing::None;
+    let mut fumble_range: u8 = DEFAULT_FUMBLE_RANGE;
+    let mut combatant: Option<String> = None;
+    let mut target: Option<String> = None;
+    let mut parry: i32 = 0;
+    let mut parry_options = ParryOptions::default();
+    let mut weapon_range: Option<u32> = None;
+    let mut distance: Option<u32> = None;
+    let mut aim = Aim::None;
+    let mut prone_target = false;
 
     for option in &command.data.options {
         match option.name.as_str() {
             "attack_bonus" => {
                 if let Some(val) = option.value.as_i64() {
-                    attack_bonus = val as i32;
+                    attack_bonus = Some(val as i32);
+                }
+            }
+            "weapon" => {
+                if let Some(val) = option.value.as_str() {
+                    weapon = Some(val.trim().to_string());
                 }
             }
             "defense" => {
                 if let Some(val) = option.value.as_i64() {
-                    defense = val as i32;
+                    defense = Some(val as i32);
+                }
+            }
+            "cover" => {
+                if let Some(val) = option.value.as_str().and_then(Cover::from_choice) {
+                    cover = val;
                 }
             }
             "damage" => {
                 if let Some(val) = option.value.as_i64() {
-                    damage_rating = val as i32;
+                    damage_rating = Some(val as i32);
+                }
+            }
+            "damage_type" => {
+                damage_type = option.value.as_str().and_then(DamageType::from_choice);
+            }
+            "armor_rating" => {
+                if let Some(val) = option.value.as_i64() {
+                    armor_rating = val as i32;
+                }
+            }
+            "pierce_armor" => {
+                if let Some(val) = option.value.as_str() {
+                    piercing = ArmorPiercing::from_choice(val).unwrap_or(ArmorPiercing::None);
+                }
+            }
+            "fumble_range" => {
+                if let Some(val) = option.value.as_i64() {
+                    fumble_range = val as u8;
+                }
+            }
+            "combatant" => {
+                if let Some(val) = option.value.as_str() {
+                    combatant = Some(val.trim().to_string());
+                }
+            }
+            "target" => {
+                if let Some(val) = option.value.as_str() {
+                    target = Some(val.trim().to_string());
+                }
+            }
+            "parry" => {
+                if let Some(val) = option.value.as_i64() {
+                    parry = val as i32;
+                }
+            }
+            "parry_against" => {
+                if let Some(val) = option.value.as_str().and_then(ParryFoes::from_choice) {
+                    parry_options.foes = val;
+                }
+            }
+            "improvised" => {
+                if let Some(val) = option.value.as_bool() {
+                    parry_options.improvised = val;
+                }
+            }
+            "weapon_range" => {
+                if let Some(val) = option.value.as_i64() {
+                    weapon_range = Some(val as u32);
+                }
+            }
+            "distance" => {
+                if let Some(val) = option.value.as_i64() {
+                    distance = Some(val as u32);
+                }
+            }
+            "aim" => {
+                if let Some(val) = option.value.as_str().and_then(Aim::from_choice) {
+                    aim = val;
+                }
+            }
+            "prone_target" => {
+                if let Some(val) = option.value.as_bool() {
+                    prone_target = val;
                 }
             }
             _ => {}
         }
     }
 
-    let total_modifier = attack_bonus - defense;
-    let (result, final_total) = roll_with_modifier(total_modifier);
+    // Without an explicit attack_bonus, pull the weapon's AB (and DR) from the sheet
+    let (weapon, attack_bonus, damage_rating) = match (attack_bonus, weapon) {
+        (Some(attack_bonus), weapon) => (weapon, attack_bonus, damage_rating.unwrap_or(0)),
+        (None, Some(weapon)) => {
+            let found = find_character(ctx, command).await.and_then(|character| {
+                character
+                    .attack(&weapon)
+                    .map(|(name, bonus)| (name.to_string(), bonus, character.dr))
+            });
+            match found {
+                Some((name, bonus, dr)) => (Some(name), bonus, damage_rating.unwrap_or(dr)),
+                None => {
+                    let message = format!(
+                        "⚠️ No weapon named **{}** on your sheet. Add it with `/char set field:attack` or give an `attack_bonus`.",
+                        weapon
+                    );
+                    send_ephemeral(ctx, command, &message).await;
+                    return;
+                }
+            }
+        }
+        (None, None) => {
+            send_ephemeral(ctx, command, "⚠️ Give an `attack_bonus` or a `weapon` from your sheet").await;
+            return;
+        }
+    };
+
+    let Some((condition_penalty, conditions_str)) =
+        condition_penalty(ctx, command, combatant.as_deref(), RollKind::Attack).await
+    else {
+        return;
+    };
+
+    // Shift AB to DM for the round, at the ratio for the attacker's situation
+    let (attack_bonus, parry_info, parried) = if parry > 0 {
+        let attacker = match acting_combatant(ctx, command, combatant.as_deref()).await {
+            Ok(attacker) => attacker,
+            Err(message) => {
+                send_ephemeral(ctx, command, &message).await;
+                return;
+            }
+        };
+        if let Some(attacker) = &attacker {
+            if let Some(reason) = attacker.parry_restriction() {
+                let message = format!("⚠️ **{}** can't Parry while {}", attacker.name, reason);
+                send_ephemeral(ctx, command, &message).await;
+                return;
+            }
+            parry_options.shield = attacker.defense.shield > 0;
+            parry_options.stunned = attacker.has_condition(ConditionKind::Stunned);
+        }
+        let allocated = match allocate_parry(attack_bonus, parry, &parry_options) {
+            Ok(allocated) => allocated,
+            Err(message) => {
+                send_ephemeral(ctx, command, &message).await;
+                return;
+            }
+        };
+        let info = format!(
+            "\n🤺 Parry: {} AB → {:+} DM this round ({})",
+            allocated.ab_spent,
+            allocated.dm_gained,
+            parry_options.describe()
+        );
+        let parried = attacker.map(|attacker| (attacker.name, allocated.dm_gained));
+        (allocated.attack_bonus, info, parried)
+    } else {
+        (attack_bonus, String::new(), None)
+    };
+
+    // Look the target up before rolling: it supplies the DM and takes the Hits
+    let target_combatant = match &target {
+        Some(target) => match find_combatant(ctx, command, target).await {
+            Ok(combatant) => Some(combatant),
+            Err(message) => {
+                send_ephemeral(ctx, command, &message).await;
+                return;
+            }
+        },
+        None => None,
+    };
+
+    // Range band and aiming for ranged attacks
+    let ranged = match (weapon_range, distance) {
+        (Some(weapon_range), Some(distance)) => match RangedModifiers::new(weapon_range, distance, aim) {
+            Ok(ranged) => Some(ranged),
+            Err(message) => {
+                send_ephemeral(ctx, command, &message).await;
+                return;
+            }
+        },
+        (None, None) if aim == Aim::None => None,
+        _ => {
+            send_ephemeral(ctx, command, "⚠️ Ranged attacks need both `weapon_range` and `distance`").await;
+            return;
+        }
+    };
+    let range_modifier = ranged.map_or(0, |r| r.total());
+
+    // An explicit defense wins; otherwise use the target's DM with its conditions
+    let (defense, defense_info) = match (defense, &target_combatant) {
+        (None, Some(combatant)) => {
+            let mut modifiers = DefenseModifiers {
+                cover,
+                ranged: ranged.is_some(),
+                ..combatant.defense_modifiers()
+            };
+            modifiers.prone |= prone_target;
+            let info = format!("\nDM ({}): {}", combatant.name, modifiers.breakdown());
+            (modifiers.total(), info)
+        }
+        (defense, _) => {
+            let base = defense.unwrap_or(0);
+            let mut items = Vec::new();
+            if cover != Cover::None {
+                items.push((cover.name(), cover.modifier()));
+            }
+            if prone_target {
+                let modifier = prone_modifier(base + cover.modifier(), ranged.is_some());
+                if modifier != 0 {
+                    let name = if ranged.is_some() { "Prone vs ranged" } else { "Prone (half DM)" };
+                    items.push((name, modifier));
+                }
+            }
+            let total = base + items.iter().map(|(_, modifier)| modifier).sum::<i32>();
+            let info = if items.is_empty() {
+                String::new()
+            } else {
+                let parts: Vec<String> = items
+                    .iter()
+                    .map(|(name, modifier)| format!("{} {:+}", name, modifier))
+                    .collect();
+                format!("\nDM: {} | {}", base, parts.join(" | "))
+            };
+            (total, info)
+        }
+    };
+
+    // Only record the Parry DM once the attack is known to go ahead
+    if let Some((name, dm)) = &parried {
+        record_parry(ctx, command, name, *dm).await;
+    }
+
+    let total_modifier = attack_bonus + condition_penalty + range_modifier - defense;
+    let mut dice = dice_source(command);
+    let (result, final_total) = roll_with_modifier(&mut dice, total_modifier, fumble_range);
     let feat_result = resolve_feat(final_total, result.is_fumble);
 
     // Format dice display without modifier (we'll show it separately)
@@ -168,145 +608,821 @@ pub async fn handle_attack(ctx: &Context, command: &CommandInteraction) {
         s
     };
 
-    let formatted_feat = format_feat_result(&feat_result, final_total);
+    let formatted_feat = format_feat_result(&feat_result, RollKind::Attack, final_total);
 
-    // Calculate damage if hit (20+)
-    let damage_info = if final_total >= 20 && damage_rating > 0 {
-        let points_over_20 = (final_total - 20).max(0);
-        let max_bonus = damage_rating * 2; // Cap bonus at 2x DR (for 3x DR total)
-        let hit_damage = damage_rating + points_over_20.min(max_bonus);
-        format!("\n💥 **Damage: {}** (DR {} + {} bonus)", hit_damage, damage_rating, points_over_20.min(max_bonus))
-    } else if final_total >= 20 {
-        String::from("\n💥 Hit! Add DR + points over 20 (max 3x DR)")
+    // Run the damage pipeline: DR + points over 20, Pierce Armor boons, then AR
+    let damage = if damage_rating > 0 {
+        resolve_damage(&feat_result, final_total, damage_rating, armor_rating, piercing)
     } else {
-        String::new()
+        None
+    };
+
+    let mut damage_info = match &damage {
+        Some(damage) => format!("\n{}\n🎯 **Hits applied: {}**", format_damage(damage), damage.hits),
+        None if feat_result.is_success() => {
+            String::from("\n💥 Hit! Add DR + points over 20 (max 3x DR), minus AR")
+        }
+        None if feat_result == FeatResult::PartialSuccess => {
+            String::from("\n💥 Partial hit! Deal DR reduced by AR")
+        }
+        None => String::new(),
+    };
+
+    // Apply the Hits to the target's tracker entry
+    if let (Some(target), Some(damage)) = (&target, &damage) {
+        match apply_hits(ctx, command, target, damage.hits).await {
+            Ok(report) => damage_info.push_str(&format!("\n{}", report)),
+            Err(message) => damage_info.push_str(&format!("\n{} (Hits not applied to the tracker)", message)),
+        }
+    }
+
+    if feat_result == FeatResult::CriticalFailure {
+        damage_info.push_str(&format!(
+            "\n🎲 Weapon fumble! Roll `/fumble` (unmodified {} within fumble range 2-{})",
+            result.unmodified_sum(),
+            fumble_range
+        ));
+    }
+
+    if feat_result.is_success() && piercing.sl_cost() > feat_result.success_levels() {
+        damage_info.push_str(&format!(
+            "\n⚠️ {} needs {} SL, not applied",
+            piercing.name(),
+            piercing.sl_cost()
+        ));
+    }
+
+    // List the criticals the remaining Success Levels can buy
+    let sl_spent = damage.as_ref().map(|d| d.sl_spent).unwrap_or(0);
+    let critical_info = match damage_type {
+        Some(damage_type) if feat_result.is_success() => format!(
+            "\n{}",
+            format_criticals(damage_type, feat_result.success_levels() - sl_spent)
+        ),
+        _ => String::new(),
+    };
+
+    let title = match &weapon {
+        Some(weapon) => format!("Attack Roll: {}", weapon),
+        None => String::from("Attack Roll"),
     };
 
-    // Show clear breakdown: dice + AB - DM = total
+    let mut modifier_calc = String::new();
+    if condition_penalty != 0 {
+        modifier_calc.push_str(&format!(" {:+} (conditions)", condition_penalty));
+    }
+    let mut range_info = String::new();
+    if let Some(ranged) = &ranged {
+        modifier_calc.push_str(&format!(" {:+} (range)", ranged.total()));
+        range_info = format!("\nRange: {}", ranged.breakdown());
+    }
+
+    // Show clear breakdown: dice + AB + conditions + range - DM = total
     let response = format!(
-        "⚔️ **Attack Roll**\n━━━━━━━━━━━━━━\nDice: {}\nCalc: {} {:+} (AB) - {} (DM) = **{}**\n━━━━━━━━━━━━━━\n{}{}",
-        dice_display, result.raw_total, attack_bonus, defense, final_total, formatted_feat, damage_info
+        "⚔️ **{}**\n━━━━━━━━━━━━━━\nDice: {}\nCalc: {} {:+} (AB){} - {} (DM) = **{}**{}{}{}{}\n━━━━━━━━━━━━━━\n{}{}{}",
+        title, dice_display, result.raw_total, attack_bonus, modifier_calc, defense, final_total, range_info, defense_info, conditions_str, parry_info, formatted_feat, damage_info, critical_info
     );
 
-    send_response(ctx, command, &response).await;
+    let success_levels = feat_result.success_levels() - sl_spent;
+    send_roll_with_boons(ctx, command, &response, &dice, RollKind::Attack, success_levels).await;
 }
 
-/// Handle the /save command - saving throw roll
-pub async fn handle_save(ctx: &Context, command: &CommandInteraction) {
-    let mut save_type = String::from("TSR");
-    let mut bonus: i32 = 0;
-    let mut attack_level: i32 = 0;
+/// Handle the /maneuver command - martial moves as Conflicting Actions
+pub async fn handle_maneuver(ctx: &Context, command: &CommandInteraction) {
+    let mut maneuver = Maneuver::Disarm;
+    let mut attacker_bonus: i32 = 0;
+    let mut defender_bonus: i32 = 0;
+    let mut attacker_name = String::from("Attacker");
+    let mut defender_name = String::from("Defender");
+    let mut parry: i32 = 0;
+    let mut escape = false;
+    let mut opening = RiposteOpening::default();
+    let mut blunt_skill = false;
+    let mut shield: Option<i32> = None;
+    let mut damage_rating: i32 = 0;
+    let mut armor_rating: i32 = 0;
 
     for option in &command.data.options {
         match option.name.as_str() {
-            "type" => {
+            "move" => {
+                if let Some(val) = option.value.as_str().and_then(Maneuver::from_choice) {
+                    maneuver = val;
+                }
+            }
+            "attacker" => {
+                if let Some(val) = option.value.as_i64() {
+                    attacker_bonus = val as i32;
+                }
+            }
+            "defender" => {
+                if let Some(val) = option.value.as_i64() {
+                    defender_bonus = val as i32;
+                }
+            }
+            "attacker_name" => {
                 if let Some(val) = option.value.as_str() {
-                    save_type = val.to_uppercase();
+                    attacker_name = val.to_string();
                 }
             }
-            "bonus" => {
+            "defender_name" => {
+                if let Some(val) = option.value.as_str() {
+                    defender_name = val.to_string();
+                }
+            }
+            "parry" => {
                 if let Some(val) = option.value.as_i64() {
-                    bonus = val as i32;
+                    parry = val as i32;
                 }
             }
-            "attack_level" => {
+            "escape" => {
+                if let Some(val) = option.value.as_bool() {
+                    escape = val;
+                }
+            }
+            "foe_damage" => {
+                if let Some(val) = option.value.as_str().and_then(RiposteOpening::from_choice) {
+                    opening = val;
+                }
+            }
+            "blunt_skill" => {
+                if let Some(val) = option.value.as_bool() {
+                    blunt_skill = val;
+                }
+            }
+            "shield" => {
                 if let Some(val) = option.value.as_i64() {
-                    attack_level = val as i32;
+                    shield = Some(val as i32);
+                }
+            }
+            "damage" => {
+                if let Some(val) = option.value.as_i64() {
+                    damage_rating = val as i32;
+                }
+            }
+            "armor" => {
+                if let Some(val) = option.value.as_i64() {
+                    armor_rating = val as i32;
                 }
             }
             _ => {}
         }
     }
 
-    let save_name = match save_type.as_str() {
-        "TSR" => "Toughness (TSR)",
-        "RSR" => "Reflex (RSR)",
-        "WSR" => "Will (WSR)",
-        _ => "Save",
+    let setup = ManeuverSetup {
+        maneuver,
+        parry,
+        escape,
+        opening,
+        blunt_skill,
+    };
+    let modifiers = setup.itemize();
+    let modifier_info = if modifiers.is_empty() {
+        String::new()
+    } else {
+        let parts: Vec<String> = modifiers
+            .iter()
+            .map(|(name, modifier)| format!("{} {:+}", name, modifier))
+            .collect();
+        format!("\nModifiers: {}", parts.join(" | "))
     };
+    let header = format!(
+        "🤺 **Maneuver: {}**\n{} · {}\n━━━━━━━━━━━━━━",
+        maneuver.name(),
+        maneuver.action(),
+        maneuver.resolution()
+    );
+    let mut dice = dice_source(command);
+
+    if maneuver.is_opposed() {
+        let attacker = roll_contestant(
+            &mut dice,
+            &Contestant {
+                name: attacker_name,
+                bonus: attacker_bonus + setup.total(),
+            },
+        );
+        let defender = roll_contestant(
+            &mut dice,
+            &Contestant {
+                name: defender_name,
+                bonus: defender_bonus,
+            },
+        );
+        let result = judge(&attacker, &defender);
+        let difference = (attacker.total - defender.total).abs();
+        let line = |roll: &ContestRoll| {
+            format!(
+                "👤 **{}**: {} = **{}** {} {}",
+                roll.contestant.name,
+                format_roll(&roll.dice, Some(roll.contestant.bonus)),
+                roll.total,
+                roll.feat_result.emoji(),
+                roll.feat_result.name()
+            )
+        };
+        let verdict = match result {
+            ManeuverResult::Won | ManeuverResult::CriticalWin => {
+                format!("🏆 **{}** wins by {}", attacker.contestant.name, difference)
+            }
+            ManeuverResult::Lost => {
+                format!("🏆 **{}** wins by {}", defender.contestant.name, difference)
+            }
+            ManeuverResult::Tied => String::from("⚔️ **Tie**"),
+            ManeuverResult::Fumbled => format!("💀 **{}** fumbles!", attacker.contestant.name),
+            ManeuverResult::FoeFumbled => format!(
+                "🏆 **{}** wins: **{}** fumbles!",
+                attacker.contestant.name, defender.contestant.name
+            ),
+        };
+        let effect = maneuver_effect(
+            maneuver,
+            result,
+            difference,
+            escape,
+            defender.feat_result == FeatResult::CriticalSuccess,
+        );
+
+        let response = format!(
+            "{}\n{}\n{}{}\n━━━━━━━━━━━━━━\n{}\n{}",
+            header,
+            line(&attacker),
+            line(&defender),
+            modifier_info,
+            verdict,
+            effect
+        );
+        send_roll_response(ctx, command, &response, &dice).await;
+        return;
+    }
 
-    let total_modifier = bonus - attack_level;
-    let (result, final_total) = roll_with_modifier(total_modifier);
-    let feat_result = resolve_feat(final_total, result.is_fumble);
+    // Riposte and Shield Bash are attacks against the defender's DM
+    if maneuver == Maneuver::ShieldBash {
+        match shield {
+            Some(shield) => damage_rating = shield_bash_damage_rating(shield),
+            None => {
+                send_ephemeral(
+                    ctx,
+                    command,
+                    "⚠️ A Shield Bash needs your shield's DM bonus: its Damage Rating is twice that",
+                )
+                .await;
+                return;
+            }
+        }
+    }
 
-    let formatted_roll = format_roll(&result, Some(total_modifier));
-    let formatted_feat = format_feat_result(&feat_result, final_total);
+    let attack_bonus = attacker_bonus + setup.total();
+    let (result, final_total) = roll_with_modifier(&mut dice, attack_bonus - defender_bonus, DEFAULT_FUMBLE_RANGE);
+    let feat_result = resolve_feat(final_total, result.is_fumble);
 
-    let attack_level_str = if attack_level != 0 {
-        format!(" | Attack Level: {}", attack_level)
+    let mut outcome = format_feat_result(&feat_result, RollKind::Attack, final_total);
+    let damage = if damage_rating > 0 {
+        resolve_damage(&feat_result, final_total, damage_rating, armor_rating, ArmorPiercing::None)
     } else {
-        String::new()
+        None
     };
+    match &damage {
+        Some(damage) => outcome.push_str(&format!("\n{}", format_damage(damage))),
+        None if feat_result.is_success() => {
+            outcome.push_str("\n💥 Hit! Add DR + points over 20 (max 3x DR), minus AR")
+        }
+        None if feat_result == FeatResult::PartialSuccess => {
+            outcome.push_str("\n💥 Partial hit! Deal DR reduced by AR")
+        }
+        None => {}
+    }
+    if feat_result == FeatResult::CriticalFailure {
+        outcome.push_str("\n🎲 Fumble! Roll `/fumble`");
+    }
+    if let (Maneuver::ShieldBash, Some(shield)) = (maneuver, shield) {
+        outcome.push_str(&format!(
+            "\n🛡️ You lose your shield's {:+} DM for the rest of the round",
+            shield
+        ));
+    }
 
     let response = format!(
-        "🛡️ **{} Save**\n━━━━━━━━━━━━━━\nDice: {}\nBonus: {:+}{}\n━━━━━━━━━━━━━━\n{}",
-        save_name, formatted_roll, bonus, attack_level_str, formatted_feat
+        "{}\n👤 **{}** vs **{}**\nDice: {}\nCalc: {} {:+} (AB) - {} (DM) = **{}**{}\n━━━━━━━━━━━━━━\n{}",
+        header,
+        attacker_name,
+        defender_name,
+        format_roll(&result, None),
+        result.raw_total,
+        attack_bonus,
+        defender_bonus,
+        final_total,
+        modifier_info,
+        outcome
     );
-
-    send_response(ctx, command, &response).await;
+    send_roll_response(ctx, command, &response, &dice).await;
 }
 
-/// Handle the /cast command - spell casting roll with Magical Resonance check
-pub async fn handle_cast(ctx: &Context, command: &CommandInteraction) {
-    let mut tier: i32 = 1;
-    let mut bonus: i32 = 0;
+/// Handle the /save command - saving throw roll
+pub async fn handle_save(ctx: &Context, command: &CommandInteraction) {
+    let mut save_type = SaveType::Toughness;
+    let mut bonus: Option<i32> = None;
+    let mut attack_level: i32 = 0;
+    let mut combatant: Option<String> = None;
 
     for option in &command.data.options {
         match option.name.as_str() {
-            "tier" => {
-                if let Some(val) = option.value.as_i64() {
-                    tier = val as i32;
+            "type" => {
+                if let Some(save) = option.value.as_str().and_then(SaveType::from_choice) {
+                    save_type = save;
                 }
             }
             "bonus" => {
                 if let Some(val) = option.value.as_i64() {
-                    bonus = val as i32;
+                    bonus = Some(val as i32);
+                }
+            }
+            "attack_level" => {
+                if let Some(val) = option.value.as_i64() {
+                    attack_level = val as i32;
+                }
+            }
+            "combatant" => {
+                if let Some(val) = option.value.as_str() {
+                    combatant = Some(val.trim().to_string());
                 }
             }
             _ => {}
         }
     }
 
-    let (result, final_total) = roll_with_modifier(bonus);
-    let feat_result = resolve_feat(final_total, result.is_fumble);
-    let formatted_roll = format_roll(&result, Some(bonus));
-    let formatted_feat = format_feat_result(&feat_result, final_total);
+    let bonus = match bonus {
+        Some(bonus) => bonus,
+        None => match find_character(ctx, command).await {
+            Some(character) => character.save_bonus(save_type),
+            None => {
+                send_ephemeral(ctx, command, "⚠️ Give your save `bonus` or create a sheet with `/char create`").await;
+                return;
+            }
+        },
+    };
+    let save_name = save_type.name();
 
-    // Check for Magical Resonance: doubles where die value ≤ tier
-    let d1 = result.base_rolls.0;
-    let d2 = result.base_rolls.1;
-    let resonance_warning = if d1 == d2 && (d1 as i32) <= tier {
-        format!("\n\n⚡ **MAGICAL RESONANCE!** Rolled double {}s (≤ Tier {}). Roll on Resonance table!", d1, tier)
+    let Some((condition_penalty, conditions_str)) =
+        condition_penalty(ctx, command, combatant.as_deref(), RollKind::Save).await
+    else {
+        return;
+    };
+
+    let mut dice = dice_source(command);
+    let save = roll_save(&mut dice, bonus + condition_penalty, attack_level);
+
+    let formatted_roll = format_roll(&save.dice, Some(save.modifier));
+    let formatted_outcome = format_save_outcome(&save.outcome, save.total);
+
+    let attack_level_str = if attack_level != 0 {
+        format!(" | Attack Level: {}", attack_level)
     } else {
         String::new()
     };
 
     let response = format!(
-        "✨ **Casting Roll** (Tier {})\n━━━━━━━━━━━━━━\nDice: {}\nBonus: {:+}\n━━━━━━━━━━━━━━\n{}{}",
-        tier, formatted_roll, bonus, formatted_feat, resonance_warning
+        "🛡️ **{} Save**\n━━━━━━━━━━━━━━\nDice: {}\nBonus: {:+}{}{}\n━━━━━━━━━━━━━━\n{}",
+        save_name, formatted_roll, bonus, attack_level_str, conditions_str, formatted_outcome
+    );
+
+    send_roll_response(ctx, command, &response, &dice).await;
+}
+
+/// Handle the /dm command - Defensive Modifier calculator
+pub async fn handle_dm(ctx: &Context, command: &CommandInteraction) {
+    // Start from the sheet's components; options override them
+    let mut modifiers = find_character(ctx, command)
+        .await
+        .map(|character| character.defense())
+        .unwrap_or_default();
+
+    for option in &command.data.options {
+        match option.name.as_str() {
+            "dexterity" => {
+                if let Some(val) = option.value.as_i64() {
+                    modifiers.dexterity = val as i32;
+                }
+            }
+            "armor_penalty" => {
+                if let Some(val) = option.value.as_i64() {
+                    modifiers.armor_penalty = val as i32;
+                }
+            }
+            "armor_quality" => {
+                if let Some(val) = option.value.as_i64() {
+                    modifiers.armor_quality = val as i32;
+                }
+            }
+            "shield" => {
+                if let Some(val) = option.value.as_i64() {
+                    modifiers.shield = val as i32;
+                }
+            }
+            "magic" => {
+                if let Some(val) = option.value.as_i64() {
+                    modifiers.magic = val as i32;
+                }
+            }
+            "other" => {
+                if let Some(val) = option.value.as_i64() {
+                    modifiers.other = val as i32;
+                }
+            }
+            "parry" => {
+                if let Some(val) = option.value.as_i64() {
+                    modifiers.parry = val as i32;
+                }
+            }
+            "cover" => {
+                if let Some(val) = option.value.as_str().and_then(Cover::from_choice) {
+                    modifiers.cover = val;
+                }
+            }
+            "held" => {
+                if let Some(val) = option.value.as_bool() {
+                    modifiers.held = val;
+                }
+            }
+            "prone" => {
+                if let Some(val) = option.value.as_bool() {
+                    modifiers.prone = val;
+                }
+            }
+            "no_shield" => {
+                if let Some(val) = option.value.as_bool() {
+                    modifiers.no_shield = val;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let items: String = modifiers
+        .itemize()
+        .iter()
+        .map(|(name, value)| format!("\n• {}: {:+}", name, value))
+        .collect();
+    let response = format!(
+        "🛡️ **Defensive Modifier**\n━━━━━━━━━━━━━━{}\n━━━━━━━━━━━━━━\n**DM: {:+}**",
+        if items.is_empty() { String::from("\nNo modifiers") } else { items },
+        modifiers.total()
     );
 
     send_response(ctx, command, &response).await;
 }
 
+/// Handle the /cast command - spell casting roll with Magical Resonance check
+pub async fn handle_cast(ctx: &Context, command: &CommandInteraction) {
+    let mut tier: i32 = 1;
+    let mut bonus: i32 = 0;
+    let mut asf: i32 = 0;
+    let mut location = CastingLocation::Normal;
+    let mut nature = SpellNature::Neutral;
+    let mut combatant: Option<String> = None;
+
+    for option in &command.data.options {
+        match option.name.as_str() {
+            "tier" => {
+                if let Some(val) = option.value.as_i64() {
+                    tier = val as i32;
+                }
+            }
+            "bonus" => {
+                if let Some(val) = option.value.as_i64() {
+                    bonus = val as i32;
+                }
+            }
+            "asf" => {
+                if let Some(val) = option.value.as_i64() {
+                    asf = val as i32;
+                }
+            }
+            "location" => {
+                if let Some(val) = option.value.as_str().and_then(CastingLocation::from_choice) {
+                    location = val;
+                }
+            }
+            "spell_kind" => {
+                if let Some(val) = option.value.as_str().and_then(SpellNature::from_choice) {
+                    nature = val;
+                }
+            }
+            "combatant" => {
+                if let Some(val) = option.value.as_str() {
+                    combatant = Some(val.trim().to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let Some((condition_penalty, conditions_str)) =
+        condition_penalty(ctx, command, combatant.as_deref(), RollKind::Cast).await
+    else {
+        return;
+    };
+
+    // Abnormal Spell Failure widens the Critical Failure range to 2 + ASF
+    let fumble_range = asf_fumble_range(asf);
+    let mut dice = dice_source(command);
+    let total_modifier = bonus + condition_penalty;
+    let (result, final_total) = roll_with_modifier(&mut dice, total_modifier, fumble_range);
+    let feat_result = resolve_feat(final_total, result.is_fumble);
+    let formatted_roll = format_roll(&result, Some(total_modifier));
+    let formatted_feat = format_feat_result(&feat_result, RollKind::Cast, final_total);
+
+    let asf_str = if asf > 0 {
+        format!(" | ASF: {:+} (fumbles on 2-{})", asf, fumble_range)
+    } else {
+        String::new()
+    };
+
+    let fumble_prompt = if feat_result == FeatResult::CriticalFailure {
+        if asf > 0 {
+            format!(
+                "\n🎲 Roll `/spellfumble tier:{} asf:{}` ({:+} from ASF)",
+                tier,
+                asf,
+                spell_fumble_modifier(asf)
+            )
+        } else {
+            format!("\n🎲 Roll `/spellfumble tier:{}`", tier)
+        }
+    } else {
+        String::new()
+    };
+
+    // Check for Magical Resonance: doubles where die value ≤ tier
+    let resonance = triggers_resonance(&result, tier);
+    let resonance_warning = if resonance {
+        format!(
+            "\n\n⚡ **MAGICAL RESONANCE!** Rolled double {}s (≤ Tier {}). Rolling on the Resonance table...",
+            result.base_rolls.0, tier
+        )
+    } else {
+        String::new()
+    };
+
+    let response = format!(
+        "✨ **Casting Roll** (Tier {})\n━━━━━━━━━━━━━━\nDice: {}\nBonus: {:+}{}{}\n━━━━━━━━━━━━━━\n{}{}{}",
+        tier, formatted_roll, bonus, asf_str, conditions_str, formatted_feat, fumble_prompt, resonance_warning
+    );
+
+    if feat_result == FeatResult::PartialSuccess {
+        send_partial_cast(ctx, command, &response, &dice).await;
+    } else {
+        send_roll_with_boons(ctx, command, &response, &dice, RollKind::Cast, feat_result.success_levels()).await;
+    }
+
+    // Follow up with the Resonance roll, drawn from the same seeded dice
+    if resonance {
+        let modifiers = ResonanceModifiers {
+            tier,
+            asf,
+            location,
+            nature,
+        };
+        let followup = roll_resonance(&mut dice, &modifiers);
+        send_followup(ctx, command, &followup).await;
+    }
+}
+
+/// Handle the /spellattack command - Bolt, Ball, Cone and Line attack spells
+pub async fn handle_spellattack(ctx: &Context, command: &CommandInteraction) {
+    let mut shape = SpellShape::Bolt;
+    let mut attack_bonus: i32 = 0;
+    let mut damage_rating: i32 = 0;
+    let mut targets = String::new();
+    let mut tier: i32 = 1;
+    let mut defense: i32 = 0;
+    let mut spell_range: Option<u32> = None;
+    let mut distance: Option<u32> = None;
+    let mut length: Option<u32> = None;
+
+    for option in &command.data.options {
+        match option.name.as_str() {
+            "shape" => {
+                if let Some(val) = option.value.as_str().and_then(SpellShape::from_choice) {
+                    shape = val;
+                }
+            }
+            "attack_bonus" => {
+                if let Some(val) = option.value.as_i64() {
+                    attack_bonus = val as i32;
+                }
+            }
+            "damage" => {
+                if let Some(val) = option.value.as_i64() {
+                    damage_rating = val as i32;
+                }
+            }
+            "targets" => {
+                if let Some(val) = option.value.as_str() {
+                    targets = val.to_string();
+                }
+            }
+            "tier" => {
+                if let Some(val) = option.value.as_i64() {
+                    tier = val as i32;
+                }
+            }
+            "defense" => {
+                if let Some(val) = option.value.as_i64() {
+                    defense = val as i32;
+                }
+            }
+            "spell_range" => {
+                if let Some(val) = option.value.as_i64() {
+                    spell_range = Some(val as u32);
+                }
+            }
+            "distance" => {
+                if let Some(val) = option.value.as_i64() {
+                    distance = Some(val as u32);
+                }
+            }
+            "length" => {
+                if let Some(val) = option.value.as_i64() {
+                    length = Some(val as u32);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let targets = match parse_targets(&targets) {
+        Ok(targets) => targets,
+        Err(message) => {
+            send_ephemeral(ctx, command, &message).await;
+            return;
+        }
+    };
+    if shape == SpellShape::Bolt && targets.len() > 1 {
+        send_ephemeral(ctx, command, "⚠️ Bolt spells hit a single target").await;
+        return;
+    }
+
+    // Bolts only have Point Blank and Short range
+    let range_modifier = match (shape, spell_range, distance) {
+        (SpellShape::Bolt, Some(spell_range), Some(distance)) => {
+            match bolt_range_modifier(spell_range, distance) {
+                Some(modifier) => modifier,
+                None => {
+                    let message = format!(
+                        "⚠️ The bolt dissipates beyond the spell's range ({}')",
+                        spell_range
+                    );
+                    send_ephemeral(ctx, command, &message).await;
+                    return;
+                }
+            }
+        }
+        _ => 0,
+    };
+
+    let total_modifier = attack_bonus + range_modifier - defense;
+    let mut dice = dice_source(command);
+    let (result, final_total) = roll_with_modifier(&mut dice, total_modifier, DEFAULT_FUMBLE_RANGE);
+    let feat_result = resolve_feat(final_total, result.is_fumble);
+    let formatted_roll = format_roll(&result, Some(total_modifier));
+    let formatted_feat = format_feat_result(&feat_result, RollKind::Attack, final_total);
+
+    let range_str = if range_modifier != 0 {
+        format!(" | Point Blank: {:+}", range_modifier)
+    } else {
+        String::new()
+    };
+    let cone_str = match (shape, length) {
+        (SpellShape::Cone, Some(length)) => {
+            format!("\nCone: {}' long, {}' wide at the far end", length, cone_width(length))
+        }
+        _ => String::new(),
+    };
+
+    // Base damage is the attack's DR plus points over 20; armor is left to each target
+    let outcome = match resolve_damage(&feat_result, final_total, damage_rating, 0, ArmorPiercing::None) {
+        None => String::from("\n💨 The spell misses: no damage"),
+        Some(damage) => {
+            let lines: Vec<String> = resolve_targets(&mut dice, shape, &targets, damage.hits, tier)
+                .iter()
+                .map(|outcome| {
+                    let zone = match shape {
+                        SpellShape::Ball | SpellShape::Cone => format!(" ({})", outcome.target.zone.name()),
+                        _ => String::new(),
+                    };
+                    let save = match &outcome.save {
+                        Some(save) => format!(
+                            " — RSR {} = {} {}",
+                            format_roll(&save.dice, Some(save.modifier)),
+                            save.total,
+                            save.outcome.label()
+                        ),
+                        None => String::new(),
+                    };
+                    let note = if outcome.note.is_empty() {
+                        String::new()
+                    } else {
+                        format!(" ({})", outcome.note)
+                    };
+                    format!(
+                        "• **{}**{}{} → **{} Hits**{}",
+                        outcome.target.name, zone, save, outcome.hits, note
+                    )
+                })
+                .collect();
+            format!(
+                "\n💥 Base damage: **{} Hits** (DR {} + {} bonus, before armor)\n━━━━━━━━━━━━━━\n**Targets** (RSR vs Tier {})\n{}",
+                damage.hits,
+                damage.damage_rating,
+                damage.bonus_hits,
+                tier,
+                lines.join("\n")
+            )
+        }
+    };
+
+    let response = format!(
+        "🔥 **Spell Attack: {}**\n*{}*\n━━━━━━━━━━━━━━\nDice: {}\nAB: {:+} | DM: {}{}{}\n━━━━━━━━━━━━━━\n{}{}",
+        shape.name(),
+        shape.description(),
+        formatted_roll,
+        attack_bonus,
+        defense,
+        range_str,
+        cone_str,
+        formatted_feat,
+        outcome
+    );
+
+    send_roll_response(ctx, command, &response, &dice).await;
+}
+
+/// Handle the /resonance command - Magical Resonance table roll
+pub async fn handle_resonance(ctx: &Context, command: &CommandInteraction) {
+    let mut modifiers = ResonanceModifiers {
+        tier: 1,
+        asf: 0,
+        location: CastingLocation::Normal,
+        nature: SpellNature::Neutral,
+    };
+
+    for option in &command.data.options {
+        match option.name.as_str() {
+            "tier" => {
+                if let Some(val) = option.value.as_i64() {
+                    modifiers.tier = val as i32;
+                }
+            }
+            "asf" => {
+                if let Some(val) = option.value.as_i64() {
+                    modifiers.asf = val as i32;
+                }
+            }
+            "location" => {
+                if let Some(val) = option.value.as_str().and_then(CastingLocation::from_choice) {
+                    modifiers.location = val;
+                }
+            }
+            "spell_kind" => {
+                if let Some(val) = option.value.as_str().and_then(SpellNature::from_choice) {
+                    modifiers.nature = val;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let mut dice = dice_source(command);
+    let response = roll_resonance(&mut dice, &modifiers);
+
+    send_roll_response(ctx, command, &response, &dice).await;
+}
+
+/// Roll on the Magical Resonance table and format the result
+fn roll_resonance(dice: &mut SeededSource, modifiers: &ResonanceModifiers) -> String {
+    let total_modifier = modifiers.total();
+    let (result, final_total) = roll_with_modifier(dice, total_modifier, DEFAULT_FUMBLE_RANGE);
+    let resonance = resolve_resonance(final_total);
+
+    format!(
+        "⚡ **Magical Resonance**\n━━━━━━━━━━━━━━\nDice: {}\nModifiers: {}\n━━━━━━━━━━━━━━\n{}",
+        format_roll(&result, Some(total_modifier)),
+        modifiers.breakdown(),
+        format_resonance_result(resonance, final_total)
+    )
+}
+
 /// Handle the /fumble command - weapon fumble table roll
 pub async fn handle_fumble(ctx: &Context, command: &CommandInteraction) {
-    let mut weapon_mod: i32 = 0;
+    let mut category = &WEAPON_FUMBLE_CATEGORIES[0];
     let mut skill_ranks: i32 = 0;
-    let mut weapon_type_display = String::from("Brawl/Hand");
+    let mut weapon_focus: i32 = 0;
 
     for option in &command.data.options {
         match option.name.as_str() {
             "weapon_type" => {
-                if let Some(val) = option.value.as_str() {
-                    (weapon_type_display, weapon_mod) = match val {
-                        "brawl" => ("Brawl/Hand".to_string(), 0),
-                        "short_edged" => ("Short edged/Long impact".to_string(), 2),
-                        "long_edged" => ("Long edged".to_string(), 4),
-                        "two_handed" => ("Two-handed/Chain".to_string(), 6),
-                        "polearm" => ("Polearm/Net/Whip".to_string(), 10),
-                        _ => ("Unknown".to_string(), 0),
-                    };
+                if let Some(found) = option.value.as_str().and_then(weapon_category) {
+                    category = found;
                 }
             }
             "skill_ranks" => {
@@ -314,29 +1430,46 @@ pub async fn handle_fumble(ctx: &Context, command: &CommandInteraction) {
                     skill_ranks = val as i32;
                 }
             }
+            "weapon_focus" => {
+                if let Some(val) = option.value.as_i64() {
+                    weapon_focus = val as i32;
+                }
+            }
             _ => {}
         }
     }
 
-    let rank_reduction = skill_ranks / 2;
-    let total_mod = weapon_mod - rank_reduction;
-    let (d1, d2, dice_total) = roll_2d10_closed();
-    let final_total = dice_total + total_mod;
+    let modifiers = WeaponFumbleModifiers {
+        category,
+        skill_ranks,
+        weapon_focus,
+    };
+    let mut dice = dice_source(command);
+    let (d1, d2, dice_total) = roll_2d10_closed(&mut dice);
+    let final_total = dice_total + modifiers.total();
 
-    let fumble_result = match final_total {
-        t if t <= 15 => "📋 Make Assessment Roll next Upkeep",
-        16..=20 => "📋 Assessment + choose 1: Drop weapon OR Take 1 SL critical to self",
-        21..=24 => "📋 Assessment + choose 2: Drop, 2 SL critical to self, Hit ally",
-        25..=29 => "📋 Assessment + choose 2: Drop, 2 SL critical, Hit ally, Weapon breaks",
-        _ => "💀 Assessment + choose 2: Drop, 3 SL critical, Hit ally, Weapon breaks",
+    let focus_str = if weapon_focus > 0 {
+        format!(" | Weapon Focus: {} ({:+})", weapon_focus, -weapon_focus)
+    } else {
+        String::new()
     };
 
     let response = format!(
-        "⚠️ **Weapon Fumble**\n━━━━━━━━━━━━━━\nDice: [{}, {}] = {}\nWeapon: {} ({:+}) | Skill ranks: {} ({:+})\n━━━━━━━━━━━━━━\n**Total: {}**\n{}",
-        d1, d2, dice_total, weapon_type_display, weapon_mod, skill_ranks, -rank_reduction, final_total, fumble_result
+        "⚠️ **Weapon Fumble**\n━━━━━━━━━━━━━━\nDice: [{}, {}] = {}\nWeapon: {} ({}, {:+}) | Skill ranks: {} ({:+}){}\n━━━━━━━━━━━━━━\n**Total: {}**\n{}",
+        d1,
+        d2,
+        dice_total,
+        category.name,
+        category.class.name(),
+        category.modifier,
+        skill_ranks,
+        -modifiers.rank_reduction(),
+        focus_str,
+        final_total,
+        resolve_weapon_fumble(final_total)
     );
 
-    send_response(ctx, command, &response).await;
+    send_roll_response(ctx, command, &response, &dice).await;
 }
 
 /// Handle the /spellfumble command - spell fumble table roll
@@ -345,9 +1478,15 @@ pub async fn handle_spellfumble(ctx: &Context, command: &CommandInteraction) {
     let mut ranks: i32 = 0;
     let mut spell_mod: i32 = 0;
     let mut spell_type = String::from("Utility");
+    let mut asf: i32 = 0;
 
     for option in &command.data.options {
         match option.name.as_str() {
+            "asf" => {
+                if let Some(val) = option.value.as_i64() {
+                    asf = val as i32;
+                }
+            }
             "tier" => {
                 if let Some(val) = option.value.as_i64() {
                     tier = val as i32;
@@ -375,8 +1514,10 @@ pub async fn handle_spellfumble(ctx: &Context, command: &CommandInteraction) {
         }
     }
 
-    let total_mod = tier + spell_mod - ranks;
-    let (d1, d2, dice_total) = roll_2d10_closed();
+    let asf_mod = spell_fumble_modifier(asf);
+    let total_mod = tier + spell_mod - ranks + asf_mod;
+    let mut dice = dice_source(command);
+    let (d1, d2, dice_total) = roll_2d10_closed(&mut dice);
     let final_total = dice_total + total_mod;
 
     let fumble_result = match final_total {
@@ -388,9 +1529,90 @@ pub async fn handle_spellfumble(ctx: &Context, command: &CommandInteraction) {
         _ => "💀 Stunned 4 rounds + choose 2: Lose triple Mana + Weary, wrong target, Resonance (+10), KO 6h",
     };
 
+    let asf_str = if asf > 0 {
+        format!(" | ASF {} (3×): {:+}", asf, asf_mod)
+    } else {
+        String::new()
+    };
+
+    let response = format!(
+        "💥 **Spell Fumble**\n━━━━━━━━━━━━━━\nDice: [{}, {}] = {}\nTier: {:+} | Type: {} ({:+}) | Ranks: {:+}{}\n━━━━━━━━━━━━━━\n**Total: {}**\n{}",
+        d1, d2, dice_total, tier, spell_type, spell_mod, -ranks, asf_str, final_total, fumble_result
+    );
+
+    send_roll_response(ctx, command, &response, &dice).await;
+}
+
+/// Handle the /asf command - Abnormal Spell Failure calculator
+pub async fn handle_asf(ctx: &Context, command: &CommandInteraction) {
+    let mut sources = AsfSources::default();
+
+    for option in &command.data.options {
+        match option.name.as_str() {
+            "spell_origin" => {
+                if let Some(val) = option.value.as_str().and_then(SpellOrigin::from_choice) {
+                    sources.origin = val;
+                }
+            }
+            "missing_ranks" => {
+                if let Some(val) = option.value.as_i64() {
+                    sources.missing_ranks = val as u8;
+                }
+            }
+            "instantaneous" => {
+                if let Some(val) = option.value.as_bool() {
+                    sources.instantaneous = val;
+                }
+            }
+            "missing_mana" => {
+                if let Some(val) = option.value.as_i64() {
+                    sources.missing_mana = val as u8;
+                }
+            }
+            "gestures" => {
+                if let Some(val) = option.value.as_str().and_then(ComponentUse::from_choice) {
+                    sources.gestures = val;
+                }
+            }
+            "incantations" => {
+                if let Some(val) = option.value.as_str().and_then(ComponentUse::from_choice) {
+                    sources.incantations = val;
+                }
+            }
+            "soul_burn" => {
+                if let Some(val) = option.value.as_i64() {
+                    sources.soul_burn = val as u8;
+                }
+            }
+            "armor" => {
+                if let Some(val) = option.value.as_i64() {
+                    sources.armor = val as u8;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let items = sources.itemize();
+    let asf = sources.total();
+
+    let breakdown = if items.is_empty() {
+        String::from("No ASF sources")
+    } else {
+        items
+            .iter()
+            .map(|(name, value)| format!("• {}: {:+}", name, value))
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+
     let response = format!(
-        "💥 **Spell Fumble**\n━━━━━━━━━━━━━━\nDice: [{}, {}] = {}\nTier: {:+} | Type: {} ({:+}) | Ranks: {:+}\n━━━━━━━━━━━━━━\n**Total: {}**\n{}",
-        d1, d2, dice_total, tier, spell_type, spell_mod, -ranks, final_total, fumble_result
+        "🌀 **Abnormal Spell Failure**\n━━━━━━━━━━━━━━\n{}\n━━━━━━━━━━━━━━\n**ASF: {:+}**\nCritical Failure range: 2-{}\nSpell Fumble modifier: {:+}\n\nUse `asf:{}` with `/cast`, `/spellfumble` and `/resonance`.",
+        breakdown,
+        asf,
+        asf_fumble_range(asf),
+        spell_fumble_modifier(asf),
+        asf
     );
 
     send_response(ctx, command, &response).await;
@@ -401,85 +1623,91 @@ pub async fn handle_help(ctx: &Context, command: &CommandInteraction) {
     let response = "📖 **Fantasy Express Commands**\n━━━━━━━━━━━━━━\n\
         **Basic Rolls**\n\
         `/roll` - 2d10 open-ended (explodes on 19-20)\n\
-        `/skill` - Skill check with FEAT resolution\n\n\
+        `/skill` - Skill check with FEAT resolution (`helpers` roll first and add their bonus)\n\
+        *Successful skill, attack and casting rolls offer a menu to spend SL on Boons*\n\n\
         **Combat**\n\
         `/initiative` - Initiative (2d10 NOT open-ended)\n\
-        `/attack` - Attack roll with damage calc\n\
-        `/save` - Saving throw (TSR/RSR/WSR)\n\n\
+        `/combat start|add|join|roll|next|show|end` - Encounter and initiative tracker\n\
+        `/condition add|remove|list` - Conditions and critical effects (applied to your rolls)\n\
+        `/upkeep` - Apply bleeding, tick down Stun/Dazed\n\
+        `/hp damage|heal|set|show` - Combatant Hits and wounds\n\
+        `/attack` - Attack roll with damage calc and criticals\n\
+        `/save` - Saving throw (TSR/RSR/WSR)\n\
+        `/dm` - Defensive Modifier calculator\n\
+        `/maneuver` - Martial moves (Disarm, Feint, Grapple...)\n\n\
         **Magic**\n\
-        `/cast` - Spell casting with Resonance check\n\n\
+        `/cast` - Spell casting with Resonance check\n\
+        `/spellattack` - Bolt, Ball, Cone and Line attack spells\n\
+        `/resonance` - Magical Resonance table\n\
+        `/asf` - Abnormal Spell Failure calculator\n\n\
         **Fumbles**\n\
         `/fumble` - Weapon fumble table\n\
         `/spellfumble` - Spell fumble table\n\n\
         **Reference**\n\
         `/difficulty` - Difficulty modifiers\n\
-        `/opposed` - Opposed roll (two participants)";
+        `/opposed` - Opposed roll (two participants)\n\
+        `/contest` - Conflicting actions, any number of participants\n\
+        `/odds` - Exact chance of each FEAT outcome\n\n\
+        **Characters**\n\
+        `/char create|set|show` - Character sheet (used by `/skill`, `/save`, `/initiative`, `/attack`)";
 
     send_response(ctx, command, response).await;
 }
 
 /// Handle the /difficulty command - show difficulty reference
 pub async fn handle_difficulty(ctx: &Context, command: &CommandInteraction) {
-    let response = "📊 **Difficulty Modifiers**\n━━━━━━━━━━━━━━\n\
-        | Difficulty | Modifier |\n\
-        |------------|----------|\n\
-        | Easy | +4 |\n\
-        | Normal | +0 |\n\
-        | Challenging | -3 |\n\
-        | Hard | -6 |\n\
-        | Very Hard | -9 |\n\
-        | Heroic | -12 |\n\
-        | Legendary | -15 |\n\
-        | Mythic | -20 |\n\n\
-        *Taking the Time: +4 (double time)*";
+    let response = format!(
+        "📊 **Difficulty Modifiers**\n━━━━━━━━━━━━━━\n{}",
+        format_difficulty_table()
+    );
 
-    send_response(ctx, command, response).await;
+    send_response(ctx, command, &response).await;
 }
 
-/// Handle the /opposed command - opposed roll between two participants
-pub async fn handle_opposed(ctx: &Context, command: &CommandInteraction) {
-    let mut bonus1: i32 = 0;
-    let mut bonus2: i32 = 0;
+/// Handle the /odds command - exact FEAT outcome probabilities for a bonus and difficulty
+pub async fn handle_odds(ctx: &Context, command: &CommandInteraction) {
+    let mut bonus: i32 = 0;
+    let mut difficulty = Difficulty::default();
+    let mut depth: u32 = DEFAULT_EXPLOSION_DEPTH;
+    let mut fumble_range: u8 = DEFAULT_FUMBLE_RANGE;
 
     for option in &command.data.options {
         match option.name.as_str() {
-            "bonus1" => {
+            "bonus" => {
+                if let Some(val) = option.value.as_i64() {
+                    bonus = val as i32;
+                }
+            }
+            "difficulty" => {
+                if let Some(val) = option.value.as_str().and_then(Difficulty::from_choice) {
+                    difficulty = val;
+                }
+            }
+            "depth" => {
                 if let Some(val) = option.value.as_i64() {
-                    bonus1 = val as i32;
+                    depth = val as u32;
                 }
             }
-            "bonus2" => {
+            "fumble_range" => {
                 if let Some(val) = option.value.as_i64() {
-                    bonus2 = val as i32;
+                    fumble_range = val as u8;
                 }
             }
             _ => {}
         }
     }
 
-    let (result1, total1) = roll_with_modifier(bonus1);
-    let (result2, total2) = roll_with_modifier(bonus2);
-
-    let formatted1 = format_roll(&result1, Some(bonus1));
-    let formatted2 = format_roll(&result2, Some(bonus2));
-
-    let winner = if result1.is_fumble && result2.is_fumble {
-        "💀 Both fumbled!"
-    } else if result1.is_fumble {
-        "💀 Participant 1 fumbled! **Participant 2 wins**"
-    } else if result2.is_fumble {
-        "💀 Participant 2 fumbled! **Participant 1 wins**"
-    } else if total1 > total2 {
-        "🏆 **Participant 1 wins!**"
-    } else if total2 > total1 {
-        "🏆 **Participant 2 wins!**"
-    } else {
-        "⚔️ **Tie!** Neither wins, re-roll or find another solution"
-    };
+    let odds = feat_odds(bonus + difficulty.modifier(), depth, fumble_range);
 
     let response = format!(
-        "⚔️ **Opposed Roll**\n━━━━━━━━━━━━━━\n👤 **P1**: {} = **{}**\n👤 **P2**: {} = **{}**\n━━━━━━━━━━━━━━\n{}",
-        formatted1, total1, formatted2, total2, winner
+        "📈 **Odds**\n━━━━━━━━━━━━━━\nBonus: {:+} | Difficulty: {} {:+} | Explosion depth: {} | Fumbles on 2-{}\n━━━━━━━━━━━━━━\n{}\n━━━━━━━━━━━━━━\n**Any success: {:.2}%**",
+        bonus,
+        difficulty.name(),
+        difficulty.modifier(),
+        depth,
+        fumble_range,
+        format_feat_odds(&odds),
+        odds.at_least_sl(1) * 100.0
     );
 
     send_response(ctx, command, &response).await;
@@ -493,67 +1721,450 @@ pub fn register_commands() -> Vec<CreateCommand> {
             .add_option(
                 CreateCommandOption::new(
                     CommandOptionType::Integer,
-                    "modifier",
-                    "Optional bonus/penalty to add (e.g. +15 for attack bonus)",
+                    "modifier",
+                    "Optional bonus/penalty to add (e.g. +15 for attack bonus)",
+                )
+                .required(false)
+                .max_int_value(100),
+            )
+            .add_option(seed_option()),
+        CreateCommand::new("skill")
+            .description("Make a skill check with bonus and difficulty")
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "bonus",
+                    "Your total skill bonus (stat + ranks + modifiers); overrides your sheet",
+                )
+                .required(false)
+                .max_int_value(100),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "skill",
+                    "Skill name from your character sheet (e.g. Stealth)",
+                )
+                .required(false)
+                .max_length(64),
+            )
+            .add_option(difficulty_option())
+            .add_option(bool_option("take_time", "Taking the Time: +4, takes at least double the time"))
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "modifier",
+                    "Other situational modifiers",
+                )
+                .required(false),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "helpers",
+                    "Helpers who roll first and add to your roll, e.g. Bram:+8, Ilsa:+5",
+                )
+                .required(false)
+                .max_length(500),
+            )
+            .add_option(combatant_option())
+            .add_option(seed_option()),
+        // Initiative command
+        CreateCommand::new("initiative")
+            .description("Roll initiative: 2d10 (not open-ended) + Dexterity + modifiers")
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "dexterity",
+                    "Your Dexterity stat (default: from your character sheet)",
+                )
+                .required(false)
+                .max_int_value(20),
+            )
+            .add_option(bool_option("weapon_not_ready", "Weapon not ready, first round (-10)"))
+            .add_option(bool_option("surprised", "Surprised, first round (-20)"))
+            .add_option(bool_option("two_weapons", "Two weapon fighting (-5)"))
+            .add_option(bool_option("heavy_weapon", "Weapon with the Heavy quality (-5)"))
+            .add_option(ShieldSize::ALL.into_iter().fold(
+                CreateCommandOption::new(CommandOptionType::String, "shield", "Shield carried")
+                    .required(false),
+                |option, shield| {
+                    option.add_string_choice(
+                        format!("{} ({:+})", shield.name(), shield.modifier()),
+                        shield.choice(),
+                    )
+                },
+            ))
+            .add_option(Encumbrance::ALL.into_iter().fold(
+                CreateCommandOption::new(CommandOptionType::String, "encumbrance", "Encumbrance")
+                    .required(false),
+                |option, encumbrance| {
+                    option.add_string_choice(
+                        format!("{} ({:+})", encumbrance.name(), encumbrance.modifier()),
+                        encumbrance.choice(),
+                    )
+                },
+            ))
+            .add_option(bool_option("wounded", "Wounded more than 50% (-10)"))
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "spell_tier",
+                    "Tier of the spell you're casting (-1 per 2 Tiers, rounded up)",
+                )
+                .required(false)
+                .min_int_value(1)
+                .max_int_value(20),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "weapon_length_steps",
+                    "Weapon length steps in your favour: shorter in close quarters, longer at melee (+2 each)",
+                )
+                .required(false)
+                .min_int_value(0)
+                .max_int_value(5),
+            )
+            .add_option(bool_option("closing", "Longer weapon when closing to combat (+5)"))
+            .add_option(seed_option()),
+        // Attack command
+        CreateCommand::new("attack")
+            .description("Make an attack roll with FEAT resolution")
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "target",
+                    "Combatant attacked: uses their DM and applies the damage to their Hits",
+                )
+                .required(false)
+                .max_length(64),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "defense",
+                    "Target's Defensive Modifier (DM); overrides the target's",
+                )
+                .required(false),
+            )
+            .add_option(cover_option())
+            .add_option(bool_option("prone_target", "Target is Prone (half DM, or +4 DM vs ranged)"))
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "attack_bonus",
+                    "Your total Attack Bonus (skill + weapon + modifiers); overrides your sheet",
+                )
+                .required(false),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "weapon",
+                    "Weapon from your character sheet (uses its Attack Bonus and your DR)",
+                )
+                .required(false)
+                .max_length(64),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "damage",
+                    "Weapon Damage Rating (DR) for damage calculation",
+                )
+                .required(false)
+                .min_int_value(1)
+                .max_int_value(50),
+            )
+            .add_option(damage_type_option())
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "armor_rating",
+                    "Target's Armor Rating (AR) against this damage type",
+                )
+                .required(false)
+                .min_int_value(0)
+                .max_int_value(50),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "pierce_armor",
+                    "Spend Success Levels to pierce the target's armor",
+                )
+                .required(false)
+                .add_string_choice("Pierce Armor (2 SL, halve AR)", "pierce")
+                .add_string_choice("Pierce Armor II (3 SL, quarter AR)", "pierce_ii")
+                .add_string_choice("Pierce Armor True (4 SL, ignore AR)", "pierce_true"),
+            )
+            .add_option(fumble_range_option())
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "weapon_range",
+                    "Ranged weapon's Range in feet",
+                )
+                .required(false)
+                .min_int_value(1)
+                .max_int_value(10000),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "distance",
+                    "Distance to the target in feet (ranged attacks)",
+                )
+                .required(false)
+                .min_int_value(0)
+                .max_int_value(40000),
+            )
+            .add_option(Aim::ALL.into_iter().fold(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "aim",
+                    "Aiming the previous round (ranged attacks)",
+                )
+                .required(false),
+                |option, aim| {
+                    option.add_string_choice(format!("{} ({:+})", aim.name(), aim.modifier()), aim.choice())
+                },
+            ))
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "parry",
+                    "AB to shift to DM for the round (1:1 single foe, 2:1 multiple)",
+                )
+                .required(false)
+                .min_int_value(1)
+                .max_int_value(100),
+            )
+            .add_option(ParryFoes::ALL.into_iter().fold(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "parry_against",
+                    "Parrying a single foe or multiple foes (default single)",
+                )
+                .required(false),
+                |option, foes| option.add_string_choice(foes.name(), foes.choice()),
+            ))
+            .add_option(bool_option("improvised", "Parrying with an improvised weapon (2:1, or 3:1 vs multiple)"))
+            .add_option(combatant_option())
+            .add_option(seed_option()),
+        // Maneuver command
+        CreateCommand::new("maneuver")
+            .description("Martial moves: Disarm, Feint, Grapple, Knock Down, Lock and more")
+            .add_option(Maneuver::ALL.into_iter().fold(
+                CreateCommandOption::new(CommandOptionType::String, "move", "Martial move")
+                    .required(true),
+                |option, maneuver| option.add_string_choice(maneuver.name(), maneuver.choice()),
+            ))
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "attacker",
+                    "Attacker's bonus for the move (AB, Deceive, Brawl...)",
+                )
+                .required(true),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "defender",
+                    "Defender's opposing bonus, or their DM for Riposte and Shield Bash",
+                )
+                .required(true),
+            )
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::String, "attacker_name", "Attacker's name")
+                    .required(false)
+                    .max_length(64),
+            )
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::String, "defender_name", "Defender's name")
+                    .required(false)
+                    .max_length(64),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "parry",
+                    "Lock: AB spent Parrying, taken off your roll",
+                )
+                .required(false)
+                .min_int_value(0)
+                .max_int_value(100),
+            )
+            .add_option(bool_option("escape", "Grapple: escaping a hold (-6)"))
+            .add_option(RiposteOpening::ALL.into_iter().fold(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "foe_damage",
+                    "Riposte: what the foe's attack did (default: no damage, +4)",
+                )
+                .required(false),
+                |option, opening| option.add_string_choice(opening.name(), opening.choice()),
+            ))
+            .add_option(bool_option("blunt_skill", "Shield Bash: using a Blunt skill instead of Brawl -4"))
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "shield",
+                    "Shield Bash: your shield's DM bonus (DR is twice this)",
+                )
+                .required(false)
+                .min_int_value(1)
+                .max_int_value(20),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "damage",
+                    "Riposte: your weapon's Damage Rating",
+                )
+                .required(false)
+                .min_int_value(1)
+                .max_int_value(100),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "armor",
+                    "Riposte/Shield Bash: target's Armor Rating",
+                )
+                .required(false)
+                .min_int_value(0)
+                .max_int_value(100),
+            )
+            .add_option(seed_option()),
+        // Save command
+        CreateCommand::new("save")
+            .description("Make a saving throw (TSR, RSR, or WSR)")
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "type",
+                    "Type of save: TSR (Toughness), RSR (Reflex), WSR (Will)",
+                )
+                .required(true)
+                .add_string_choice(SaveType::Toughness.name(), SaveType::Toughness.choice())
+                .add_string_choice(SaveType::Reflex.name(), SaveType::Reflex.choice())
+                .add_string_choice(SaveType::Will.name(), SaveType::Will.choice()),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "bonus",
+                    "Your total save bonus (default: 7 + Stat + Kin + Level from your sheet)",
                 )
-                .required(false)
-                .max_int_value(100),
-            ),
-        CreateCommand::new("skill")
-            .description("Make a skill check with bonus and difficulty")
+                .required(false),
+            )
             .add_option(
                 CreateCommandOption::new(
                     CommandOptionType::Integer,
-                    "bonus",
-                    "Your total skill bonus (stat + ranks + modifiers)",
+                    "attack_level",
+                    "Attack Level/Tier of the effect you're saving against",
                 )
-                .required(true)
-                .max_int_value(100),
+                .required(false)
+                .min_int_value(0)
+                .max_int_value(20),
+            )
+            .add_option(combatant_option())
+            .add_option(seed_option()),
+        // Defensive Modifier command
+        CreateCommand::new("dm")
+            .description("Calculate your Defensive Modifier (defaults from your sheet)")
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::Integer, "dexterity", "Dexterity bonus")
+                    .required(false),
             )
             .add_option(
                 CreateCommandOption::new(
                     CommandOptionType::Integer,
-                    "difficulty",
-                    "Difficulty modifier (Easy +4, Normal 0, Hard -6, Heroic -12, etc.)",
+                    "armor_penalty",
+                    "Armor Dexterity penalty (subtracted)",
                 )
                 .required(false)
-            ),
-        // Initiative command
-        CreateCommand::new("initiative")
-            .description("Roll initiative: 2d10 (not open-ended) + Dexterity + modifiers")
+                .min_int_value(0)
+                .max_int_value(50),
+            )
             .add_option(
                 CreateCommandOption::new(
                     CommandOptionType::Integer,
-                    "dexterity",
-                    "Your Dexterity stat",
+                    "armor_quality",
+                    "Armor Quality/Magic bonus",
                 )
-                .required(true)
-                .max_int_value(20),
+                .required(false),
+            )
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::Integer, "shield", "Shield DM bonus")
+                    .required(false),
+            )
+            .add_option(
+                CreateCommandOption::new(CommandOptionType::Integer, "magic", "Magic item bonuses")
+                    .required(false),
             )
             .add_option(
                 CreateCommandOption::new(
                     CommandOptionType::Integer,
-                    "modifier",
-                    "Additional modifiers (weapon, encumbrance, wounded, etc.)",
+                    "other",
+                    "Other bonuses (Dim Light, etc.)",
                 )
                 .required(false),
-            ),
-        // Attack command
-        CreateCommand::new("attack")
-            .description("Make an attack roll with FEAT resolution")
+            )
+            .add_option(cover_option())
             .add_option(
                 CreateCommandOption::new(
                     CommandOptionType::Integer,
-                    "attack_bonus",
-                    "Your total Attack Bonus (skill + weapon + modifiers)",
+                    "parry",
+                    "DM gained from Parrying",
+                )
+                .required(false),
+            )
+            .add_option(bool_option("held", "Held (-6 DM)"))
+            .add_option(bool_option("prone", "Prone (half DM)"))
+            .add_option(bool_option("no_shield", "Surprised or attacked On Rear (no Shield bonus)")),
+        // Cast command
+        CreateCommand::new("cast")
+            .description("Cast a spell with Magical Resonance detection")
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "tier",
+                    "Spell Tier (1-10)",
+                )
+                .required(true)
+                .min_int_value(1)
+                .max_int_value(10),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "bonus",
+                    "Your total casting bonus (skill + modifiers)",
                 )
                 .required(true),
             )
+            .add_option(asf_option())
+            .add_option(location_option())
+            .add_option(spell_kind_option())
+            .add_option(combatant_option())
+            .add_option(seed_option()),
+        // Spell attack command
+        CreateCommand::new("spellattack")
+            .description("Attack spell: roll the attack, then each target's RSR")
+            .add_option(SpellShape::ALL.into_iter().fold(
+                CreateCommandOption::new(CommandOptionType::String, "shape", "Spell shape")
+                    .required(true),
+                |option, shape| option.add_string_choice(shape.name(), shape.choice()),
+            ))
             .add_option(
                 CreateCommandOption::new(
                     CommandOptionType::Integer,
-                    "defense",
-                    "Target's Defensive Modifier (DM)",
+                    "attack_bonus",
+                    "Attack Bonus (higher of Ranged or Arcana)",
                 )
                 .required(true),
             )
@@ -561,47 +2172,73 @@ pub fn register_commands() -> Vec<CreateCommand> {
                 CreateCommandOption::new(
                     CommandOptionType::Integer,
                     "damage",
-                    "Weapon Damage Rating (DR) for damage calculation",
+                    "Spell's Damage Rating (DR)",
                 )
-                .required(false)
+                .required(true)
                 .min_int_value(1)
-                .max_int_value(50),
-            ),
-        // Save command
-        CreateCommand::new("save")
-            .description("Make a saving throw (TSR, RSR, or WSR)")
+                .max_int_value(100),
+            )
             .add_option(
                 CreateCommandOption::new(
                     CommandOptionType::String,
-                    "type",
-                    "Type of save: TSR (Toughness), RSR (Reflex), WSR (Will)",
+                    "targets",
+                    "Targets and RSR bonuses, e.g. Goblin:+5:center, Orc:+3, Troll:+8:edge",
                 )
                 .required(true)
-                .add_string_choice("Toughness (TSR)", "TSR")
-                .add_string_choice("Reflex (RSR)", "RSR")
-                .add_string_choice("Will (WSR)", "WSR"),
+                .max_length(500),
             )
             .add_option(
                 CreateCommandOption::new(
                     CommandOptionType::Integer,
-                    "bonus",
-                    "Your total save bonus (7 + Stat + Kin + Level + mods)",
+                    "tier",
+                    "Spell Tier, subtracted from the targets' RSR (default 1)",
                 )
-                .required(true),
+                .required(false)
+                .min_int_value(1)
+                .max_int_value(20),
             )
             .add_option(
                 CreateCommandOption::new(
                     CommandOptionType::Integer,
-                    "attack_level",
-                    "Attack Level/Tier of the effect you're saving against",
+                    "defense",
+                    "DM of the targeted enemy (0 when targeting a space)",
+                )
+                .required(false),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "spell_range",
+                    "Bolt: spell's range in feet",
+                )
+                .required(false)
+                .min_int_value(1)
+                .max_int_value(10000),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "distance",
+                    "Bolt: distance to the target in feet",
                 )
                 .required(false)
                 .min_int_value(0)
-                .max_int_value(20),
-            ),
-        // Cast command
-        CreateCommand::new("cast")
-            .description("Cast a spell with Magical Resonance detection")
+                .max_int_value(40000),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "length",
+                    "Cone: length in feet",
+                )
+                .required(false)
+                .min_int_value(5)
+                .max_int_value(1000),
+            )
+            .add_option(seed_option()),
+        // Resonance command
+        CreateCommand::new("resonance")
+            .description("Roll on the Magical Resonance table")
             .add_option(
                 CreateCommandOption::new(
                     CommandOptionType::Integer,
@@ -612,40 +2249,35 @@ pub fn register_commands() -> Vec<CreateCommand> {
                 .min_int_value(1)
                 .max_int_value(10),
             )
-            .add_option(
-                CreateCommandOption::new(
-                    CommandOptionType::Integer,
-                    "bonus",
-                    "Your total casting bonus (skill + modifiers)",
-                )
-                .required(true),
-            ),
+            .add_option(asf_option())
+            .add_option(location_option())
+            .add_option(spell_kind_option())
+            .add_option(seed_option()),
         // Fumble command
         CreateCommand::new("fumble")
             .description("Roll on the weapon fumble table")
+            .add_option(weapon_type_option())
             .add_option(
                 CreateCommandOption::new(
-                    CommandOptionType::String,
-                    "weapon_type",
-                    "Type of weapon",
+                    CommandOptionType::Integer,
+                    "skill_ranks",
+                    "Your ranks in the weapon skill (-1 per 2 ranks)",
                 )
-                .required(true)
-                .add_string_choice("Brawl/Hand/Short impact (+0)", "brawl")
-                .add_string_choice("Short edged/Long impact (+2)", "short_edged")
-                .add_string_choice("Long edged (+4)", "long_edged")
-                .add_string_choice("Two-handed/Chain (+6)", "two_handed")
-                .add_string_choice("Polearm/Net/Whip (+10)", "polearm"),
+                .required(false)
+                .min_int_value(0)
+                .max_int_value(20),
             )
             .add_option(
                 CreateCommandOption::new(
                     CommandOptionType::Integer,
-                    "skill_ranks",
-                    "Your ranks in the weapon skill (-1 per 2 ranks)",
+                    "weapon_focus",
+                    "Number of Weapon Focus talents for this weapon (-1 each)",
                 )
                 .required(false)
                 .min_int_value(0)
-                .max_int_value(20),
-            ),
+                .max_int_value(10),
+            )
+            .add_option(seed_option()),
         // Spell Fumble command
         CreateCommand::new("spellfumble")
             .description("Roll on the spell fumble table")
@@ -681,6 +2313,95 @@ pub fn register_commands() -> Vec<CreateCommand> {
                 .add_string_choice("Enchantment (+4)", "enchantment")
                 .add_string_choice("Alteration (+6)", "alteration")
                 .add_string_choice("Dark/Elemental (+6)", "dark"),
+            )
+            .add_option(asf_option())
+            .add_option(seed_option()),
+        // ASF command
+        CreateCommand::new("asf")
+            .description("Calculate Abnormal Spell Failure (ASF) for a spell")
+            .add_option(SpellOrigin::ALL.into_iter().fold(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "spell_origin",
+                    "Where the spell comes from",
+                )
+                .required(false),
+                |option, origin| {
+                    option.add_string_choice(
+                        format!("{} (+{})", origin.name(), origin.asf()),
+                        origin.choice(),
+                    )
+                },
+            ))
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "missing_ranks",
+                    "Overcasting: ranks short of 2 × Tier",
+                )
+                .required(false)
+                .min_int_value(0)
+                .max_int_value(20),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Boolean,
+                    "instantaneous",
+                    "Overcast spell is instantaneous (+2 per missing rank)",
+                )
+                .required(false),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "missing_mana",
+                    "Undercasting: Mana Points short (+3 each)",
+                )
+                .required(false)
+                .min_int_value(0)
+                .max_int_value(10),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "gestures",
+                    "Casting gestures",
+                )
+                .required(false)
+                .add_string_choice("Normal (+0)", "normal")
+                .add_string_choice("Subtle Gestures (+2)", "reduced")
+                .add_string_choice("No Gestures (+4)", "omitted"),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::String,
+                    "incantations",
+                    "Casting incantations",
+                )
+                .required(false)
+                .add_string_choice("Normal (+0)", "normal")
+                .add_string_choice("Quiet Incantations (+2)", "reduced")
+                .add_string_choice("No Incantations (+4)", "omitted"),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "soul_burn",
+                    "Soul Damage taken for Soul Burn (+5 each)",
+                )
+                .required(false)
+                .min_int_value(0)
+                .max_int_value(10),
+            )
+            .add_option(
+                CreateCommandOption::new(
+                    CommandOptionType::Integer,
+                    "armor",
+                    "ASF from worn armor",
+                )
+                .required(false)
+                .min_int_value(0)
+                .max_int_value(30),
             ),
         // Help command
         CreateCommand::new("help")
@@ -688,28 +2409,270 @@ pub fn register_commands() -> Vec<CreateCommand> {
         // Difficulty command
         CreateCommand::new("difficulty")
             .description("Show difficulty modifier reference table"),
-        // Opposed roll command
-        CreateCommand::new("opposed")
-            .description("Make an opposed roll between two participants")
+        contest::register_opposed(),
+        contest::register_contest(),
+        // Odds command
+        CreateCommand::new("odds")
+            .description("Show the exact chance of each FEAT outcome")
             .add_option(
                 CreateCommandOption::new(
                     CommandOptionType::Integer,
-                    "bonus1",
-                    "Participant 1's bonus",
+                    "bonus",
+                    "Your total bonus (skill, attack, save, casting...)",
                 )
-                .required(true),
+                .required(true)
+                .max_int_value(100),
             )
+            .add_option(difficulty_option())
             .add_option(
                 CreateCommandOption::new(
                     CommandOptionType::Integer,
-                    "bonus2",
-                    "Participant 2's bonus",
+                    "depth",
+                    "Chained explosions to follow before truncating (default 5)",
                 )
-                .required(true),
-            ),
+                .required(false)
+                .min_int_value(0)
+                .max_int_value(10),
+            )
+            .add_option(fumble_range_option()),
+        // Character sheet command
+        character::register_char(),
+        // Combat tracker command
+        combat::register_combat(),
+        combat::register_condition(),
+        combat::register_upkeep(),
+        combat::register_hp(),
     ]
 }
 
+/// Condition penalty and its itemized display for a roll by a combatant
+///
+/// Replies with an error and returns None if a named combatant can't be found.
+async fn condition_penalty(
+    ctx: &Context,
+    command: &CommandInteraction,
+    combatant: Option<&str>,
+    kind: RollKind,
+) -> Option<(i32, String)> {
+    match roll_conditions(ctx, command, combatant, kind).await {
+        Ok(Some((name, penalties))) => Some((
+            penalties.total(),
+            format_roll_conditions(&name, &penalties),
+        )),
+        Ok(None) => Some((0, String::new())),
+        Err(message) => {
+            send_ephemeral(ctx, command, &message).await;
+            None
+        }
+    }
+}
+
+/// Build the dice source for a command
+///
+/// Replays the `seed` option when given, otherwise starts from a fresh random seed.
+fn dice_source(command: &CommandInteraction) -> SeededSource {
+    // Subcommands carry their own options
+    let options = match command.data.options.first().map(|opt| &opt.value) {
+        Some(CommandDataOptionValue::SubCommand(options)) => options,
+        _ => &command.data.options,
+    };
+    options
+        .iter()
+        .find(|opt| opt.name == "seed")
+        .and_then(|opt| opt.value.as_i64())
+        .map(|seed| SeededSource::new(seed as u64))
+        .unwrap_or_else(SeededSource::from_entropy)
+}
+
+/// Optional boolean option
+fn bool_option(name: &str, description: &str) -> CreateCommandOption {
+    CreateCommandOption::new(CommandOptionType::Boolean, name, description).required(false)
+}
+
+/// Optional `damage_type` option listing the critical table columns
+fn damage_type_option() -> CreateCommandOption {
+    DamageType::ALL.into_iter().fold(
+        CreateCommandOption::new(
+            CommandOptionType::String,
+            "damage_type",
+            "Damage type, to list the criticals your Success Levels can buy",
+        )
+        .required(false),
+        |option, damage_type| option.add_string_choice(damage_type.name(), damage_type.choice()),
+    )
+}
+
+/// Required `weapon_type` option listing both columns of the weapon fumble table
+fn weapon_type_option() -> CreateCommandOption {
+    WEAPON_FUMBLE_CATEGORIES.iter().fold(
+        CreateCommandOption::new(CommandOptionType::String, "weapon_type", "Type of weapon")
+            .required(true),
+        |option, category| {
+            option.add_string_choice(
+                format!("{} ({:+})", category.name, category.modifier),
+                category.key,
+            )
+        },
+    )
+}
+
+/// Optional `fumble_range` option for weapons that fumble above an unmodified 2
+fn fumble_range_option() -> CreateCommandOption {
+    CreateCommandOption::new(
+        CommandOptionType::Integer,
+        "fumble_range",
+        "Critical Failure range: unmodified 2 up to this value fumbles (default 2)",
+    )
+    .required(false)
+    .min_int_value(2)
+    .max_int_value(10)
+}
+
+/// Optional `asf` option for Abnormal Spell Failure
+fn asf_option() -> CreateCommandOption {
+    CreateCommandOption::new(
+        CommandOptionType::Integer,
+        "asf",
+        "Abnormal Spell Failure modifiers (see /asf)",
+    )
+    .required(false)
+    .min_int_value(0)
+    .max_int_value(50)
+}
+
+/// Optional `location` option for the Resonance roll
+fn location_option() -> CreateCommandOption {
+    CastingLocation::ALL.into_iter().fold(
+        CreateCommandOption::new(
+            CommandOptionType::String,
+            "location",
+            "Where the spell is cast (affects Magical Resonance)",
+        )
+        .required(false),
+        |option, location| {
+            option.add_string_choice(
+                format!("{} ({:+})", location.name(), location.modifier()),
+                location.choice(),
+            )
+        },
+    )
+}
+
+/// Optional `spell_kind` option for the Resonance roll
+fn spell_kind_option() -> CreateCommandOption {
+    SpellNature::ALL.into_iter().fold(
+        CreateCommandOption::new(
+            CommandOptionType::String,
+            "spell_kind",
+            "Kind of spell (affects Magical Resonance)",
+        )
+        .required(false),
+        |option, nature| {
+            option.add_string_choice(
+                format!("{} ({:+})", nature.name(), nature.modifier()),
+                nature.choice(),
+            )
+        },
+    )
+}
+
+/// Optional `difficulty` option listing the named difficulties
+fn difficulty_option() -> CreateCommandOption {
+    Difficulty::ALL.into_iter().fold(
+        CreateCommandOption::new(CommandOptionType::String, "difficulty", "Difficulty (default: Normal)")
+            .required(false),
+        |option, difficulty| {
+            option.add_string_choice(
+                format!("{} ({:+})", difficulty.name(), difficulty.modifier()),
+                difficulty.choice(),
+            )
+        },
+    )
+}
+
+/// Optional `cover` option listing the cover between attacker and defender
+fn cover_option() -> CreateCommandOption {
+    Cover::ALL.into_iter().fold(
+        CreateCommandOption::new(CommandOptionType::String, "cover", "Cover between attacker and defender")
+            .required(false),
+        |option, cover| {
+            option.add_string_choice(
+                format!("{} ({:+})", cover.name(), cover.modifier()),
+                cover.choice(),
+            )
+        },
+    )
+}
+
+/// Optional `combatant` option for applying condition penalties
+fn combatant_option() -> CreateCommandOption {
+    CreateCommandOption::new(
+        CommandOptionType::String,
+        "combatant",
+        "Apply this combatant's condition penalties (default: the one you joined as)",
+    )
+    .required(false)
+    .max_length(64)
+}
+
+/// Optional `seed` option for replaying a roll
+fn seed_option() -> CreateCommandOption {
+    CreateCommandOption::new(
+        CommandOptionType::Integer,
+        "seed",
+        "Replay a previous roll by its seed",
+    )
+    .required(false)
+    .min_int_value(0)
+    .max_int_value(u32::MAX as u64)
+}
+
+/// Send a roll response with the seed needed to replay it
+async fn send_roll_response(
+    ctx: &Context,
+    command: &CommandInteraction,
+    content: &str,
+    dice: &SeededSource,
+) {
+    let content = format!("{}\n-# 🌱 Seed: {}", content, dice.seed());
+    send_response(ctx, command, &content).await;
+}
+
+/// Send a follow-up message after the initial response
+async fn send_followup(ctx: &Context, command: &CommandInteraction, content: &str) {
+    let followup = CreateInteractionResponseFollowup::new().content(content);
+
+    if let Err(e) = command.create_followup(&ctx.http, followup).await {
+        eprintln!("Error sending follow-up: {:?}", e);
+    }
+}
+
+/// Send a response only the invoking user can see
+async fn send_ephemeral(ctx: &Context, command: &CommandInteraction, content: &str) {
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(content)
+            .ephemeral(true),
+    );
+
+    if let Err(e) = command.create_response(&ctx.http, response).await {
+        eprintln!("Error sending response: {:?}", e);
+    }
+}
+
+/// Reply to a button or menu with a message only the clicking user can see
+async fn send_component_ephemeral(ctx: &Context, component: &ComponentInteraction, content: &str) {
+    let response = CreateInteractionResponse::Message(
+        CreateInteractionResponseMessage::new()
+            .content(content)
+            .ephemeral(true),
+    );
+
+    if let Err(e) = component.create_response(&ctx.http, response).await {
+        eprintln!("Error sending response: {:?}", e);
+    }
+}
+
 /// Send a response to a slash command interaction
 async fn send_response(ctx: &Context, command: &CommandInteraction, content: &str) {
     let response = CreateInteractionResponse::Message(
@@ -0,0 +1,234 @@
+//! Abnormal Spell Failure (ASF) for Fantasy Express spell casting
+//!
+//! Casting outside normal conditions adds ASF modifiers, which:
+//! - Widen the Critical Failure range to 2 + ASF
+//! - Add 3 × ASF to the Spell Fumble roll
+//! - Add ASF to the Magical Resonance roll
+
+use super::roll::DEFAULT_FUMBLE_RANGE;
+
+/// Where the spell comes from relative to the caster's magic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpellOrigin {
+    /// Spell from the caster's Codex/Folio
+    #[default]
+    Codex,
+    /// Within the caster's School but outside Codex/Folio (+5)
+    OutsideCodex,
+    /// From a different School (+10)
+    OtherSchool,
+}
+
+impl SpellOrigin {
+    /// All origins, in table order
+    pub const ALL: [SpellOrigin; 3] = [
+        SpellOrigin::Codex,
+        SpellOrigin::OutsideCodex,
+        SpellOrigin::OtherSchool,
+    ];
+
+    /// Parse a slash command choice value
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|o| o.choice() == value)
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            SpellOrigin::Codex => "codex",
+            SpellOrigin::OutsideCodex => "outside_codex",
+            SpellOrigin::OtherSchool => "other_school",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            SpellOrigin::Codex => "Own Codex/Folio",
+            SpellOrigin::OutsideCodex => "Outside Codex/Folio",
+            SpellOrigin::OtherSchool => "Different School",
+        }
+    }
+
+    /// ASF modifier
+    pub fn asf(&self) -> i32 {
+        match self {
+            SpellOrigin::Codex => 0,
+            SpellOrigin::OutsideCodex => 5,
+            SpellOrigin::OtherSchool => 10,
+        }
+    }
+}
+
+/// How fully gestures or incantations are performed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ComponentUse {
+    #[default]
+    Normal,
+    /// Subtle gestures / quiet incantations (+2)
+    Reduced,
+    /// No gestures / no incantations (+4)
+    Omitted,
+}
+
+impl ComponentUse {
+    /// All component uses, in table order
+    pub const ALL: [ComponentUse; 3] = [
+        ComponentUse::Normal,
+        ComponentUse::Reduced,
+        ComponentUse::Omitted,
+    ];
+
+    /// Parse a slash command choice value
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.choice() == value)
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            ComponentUse::Normal => "normal",
+            ComponentUse::Reduced => "reduced",
+            ComponentUse::Omitted => "omitted",
+        }
+    }
+
+    /// ASF modifier
+    pub fn asf(&self) -> i32 {
+        match self {
+            ComponentUse::Normal => 0,
+            ComponentUse::Reduced => 2,
+            ComponentUse::Omitted => 4,
+        }
+    }
+}
+
+/// Every documented source of Abnormal Spell Failure
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AsfSources {
+    pub origin: SpellOrigin,
+    /// Skill ranks short of 2 × Tier when overcasting
+    pub missing_ranks: u8,
+    /// Whether the overcast spell is instantaneous (+2 per missing rank instead of +1)
+    pub instantaneous: bool,
+    /// Mana Points short when undercasting (+3 each)
+    pub missing_mana: u8,
+    pub gestures: ComponentUse,
+    pub incantations: ComponentUse,
+    /// Soul Damage taken for Soul Burn (+5 each)
+    pub soul_burn: u8,
+    /// ASF from worn armor (varies by armor)
+    pub armor: u8,
+}
+
+impl AsfSources {
+    /// Each ASF source that applies, with its modifier
+    pub fn itemize(&self) -> Vec<(String, i32)> {
+        let mut items = Vec::new();
+
+        if self.origin.asf() != 0 {
+            items.push((self.origin.name().to_string(), self.origin.asf()));
+        }
+        if self.missing_ranks > 0 {
+            let per_rank = if self.instantaneous { 2 } else { 1 };
+            items.push((
+                format!("Overcast ({} missing ranks)", self.missing_ranks),
+                per_rank * self.missing_ranks as i32,
+            ));
+        }
+        if self.missing_mana > 0 {
+            items.push((
+                format!("Undercast ({} missing Mana)", self.missing_mana),
+                3 * self.missing_mana as i32,
+            ));
+        }
+        let gestures = match self.gestures {
+            ComponentUse::Normal => None,
+            ComponentUse::Reduced => Some("Subtle Gestures"),
+            ComponentUse::Omitted => Some("No Gestures"),
+        };
+        if let Some(name) = gestures {
+            items.push((name.to_string(), self.gestures.asf()));
+        }
+        let incantations = match self.incantations {
+            ComponentUse::Normal => None,
+            ComponentUse::Reduced => Some("Quiet Incantations"),
+            ComponentUse::Omitted => Some("No Incantations"),
+        };
+        if let Some(name) = incantations {
+            items.push((name.to_string(), self.incantations.asf()));
+        }
+        if self.soul_burn > 0 {
+            items.push((
+                format!("Soul Burn ({} Soul Damage)", self.soul_burn),
+                5 * self.soul_burn as i32,
+            ));
+        }
+        if self.armor > 0 {
+            items.push(("Armor".to_string(), self.armor as i32));
+        }
+
+        items
+    }
+
+    /// Total ASF modifier
+    pub fn total(&self) -> i32 {
+        self.itemize().iter().map(|(_, asf)| asf).sum()
+    }
+}
+
+/// Critical Failure range for a casting roll: 2 + ASF
+pub fn fumble_range(asf: i32) -> u8 {
+    (DEFAULT_FUMBLE_RANGE as i32 + asf.max(0)).min(u8::MAX as i32) as u8
+}
+
+/// Spell Fumble roll modifier from ASF: 3 × ASF
+pub fn spell_fumble_modifier(asf: i32) -> i32 {
+    3 * asf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_asf_by_default() {
+        let sources = AsfSources::default();
+        assert!(sources.itemize().is_empty());
+        assert_eq!(sources.total(), 0);
+        assert_eq!(fumble_range(sources.total()), 2);
+    }
+
+    #[test]
+    fn test_all_sources() {
+        let sources = AsfSources {
+            origin: SpellOrigin::OtherSchool,
+            missing_ranks: 2,
+            instantaneous: false,
+            missing_mana: 1,
+            gestures: ComponentUse::Reduced,
+            incantations: ComponentUse::Omitted,
+            soul_burn: 1,
+            armor: 3,
+        };
+        assert_eq!(sources.total(), 10 + 2 + 3 + 2 + 4 + 5 + 3);
+        assert_eq!(sources.itemize().len(), 7);
+    }
+
+    #[test]
+    fn test_instantaneous_overcast_doubles() {
+        let sources = AsfSources {
+            missing_ranks: 3,
+            instantaneous: true,
+            ..Default::default()
+        };
+        assert_eq!(sources.total(), 6);
+    }
+
+    #[test]
+    fn test_fumble_effects() {
+        assert_eq!(fumble_range(4), 6);
+        assert_eq!(fumble_range(-1), 2);
+        assert_eq!(spell_fumble_modifier(4), 12);
+    }
+}
@@ -0,0 +1,337 @@
+//! Success Level boons for Fantasy Express
+//!
+//! A success leaves Success Levels to spend on boons from the skill, combat
+//! or casting table. Boons marked `+` can be bought again: after the first
+//! purchase, each additional SL adds another instance. Only one of the boons
+//! marked `*` may apply at a time.
+//!
+//! Combat criticals aren't listed here: their SL cost depends on the
+//! target's armor, so `/attack` shows what the SL can buy instead. Pierce
+//! Armor is applied by `/attack` itself, before damage is worked out.
+
+use super::feat::RollKind;
+
+/// How often a boon can be bought
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoonLimit {
+    /// Once per roll
+    Once,
+    /// `*`: only one of these may apply at a time
+    Exclusive,
+    /// `+`: each additional SL adds another instance, up to `max` if any
+    Cumulative { max: Option<u8> },
+}
+
+/// An entry in a boon table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Boon {
+    pub name: &'static str,
+    /// SL cost of the first instance
+    pub cost: u8,
+    pub limit: BoonLimit,
+    pub effect: &'static str,
+}
+
+const fn boon(name: &'static str, cost: u8, limit: BoonLimit, effect: &'static str) -> Boon {
+    Boon {
+        name,
+        cost,
+        limit,
+        effect,
+    }
+}
+
+const ONCE: BoonLimit = BoonLimit::Once;
+const EXCLUSIVE: BoonLimit = BoonLimit::Exclusive;
+const STACKS: BoonLimit = BoonLimit::Cumulative { max: None };
+
+/// Success Level Boons (Skills)
+pub const SKILL_BOONS: [Boon; 14] = [
+    boon("Extra Information", 1, ONCE, "Learn extra relevant information"),
+    boon("Head Start", 1, EXCLUSIVE, "+2 bonus on a related next task"),
+    boon(
+        "Better Result",
+        1,
+        BoonLimit::Cumulative { max: Some(5) },
+        "Increase the result by 10% per SL (max 50%)",
+    ),
+    boon("Hard to Resist", 1, ONCE, "Targets get -2 to saves against this"),
+    boon("Quietly", 1, EXCLUSIVE, "Task performed quietly (+2 to stealth)"),
+    boon(
+        "Quicker",
+        1,
+        BoonLimit::Cumulative { max: Some(5) },
+        "Reduce the time by 10% per SL (max 50%)",
+    ),
+    boon("Useful Information", 2, ONCE, "Gain useful adventure information"),
+    boon("Lucky Break", 2, EXCLUSIVE, "+4 on the next die roll (any purpose)"),
+    boon("Stunning Success", 2, ONCE, "Onlookers are stunned for 1 round"),
+    boon(
+        "Knack",
+        3,
+        EXCLUSIVE,
+        "+2 to future uses of this skill until a Critical Failure",
+    ),
+    boon("Important Information", 3, ONCE, "Gain important adventure information"),
+    boon("On a Roll", 3, EXCLUSIVE, "+2 to all rolls for 24 hours"),
+    boon("Teamwork", 4, ONCE, "Character and allies get +3 on their next roll"),
+    boon(
+        "Inspiring",
+        5,
+        ONCE,
+        "Character and allies within 50' get +4 on their next roll",
+    ),
+];
+
+/// Combat Options (Boons), without Pierce Armor
+pub const COMBAT_BOONS: [Boon; 21] = [
+    boon("Combat Shuffle", 1, STACKS, "Move foe or self 5' per SL"),
+    boon("Defensive Posture", 1, ONCE, "+2 DM next round"),
+    boon("Disarm Foe", 1, ONCE, "Foe makes a TSR/RSR or their weapon flies 1-10'"),
+    boon("Hold Foe", 1, ONCE, "Foe makes an RSR/TSR (Brawn) or is Held"),
+    boon("Initiative Boost", 1, STACKS, "+2 Initiative next round per SL"),
+    boon("Knock Prone", 1, ONCE, "Foe makes an RSR or falls Prone"),
+    boon("Rapid Reload", 1, ONCE, "Free reload action"),
+    boon("Attack Boost", 2, STACKS, "+1 AB next round per SL"),
+    boon("Improved Hold", 2, ONCE, "Foe is Held, no save this round"),
+    boon("Inspiring Attack", 2, STACKS, "Allies get +1 Initiative per SL"),
+    boon("Lightning Strike", 2, ONCE, "Second attack at -4"),
+    boon(
+        "Advantageous Attack",
+        3,
+        ONCE,
+        "Attack from On Rear next round (+6 AB)",
+    ),
+    boon("Disarm Foe II", 3, ONCE, "Automatic disarm, no save"),
+    boon("Double Shot", 3, ONCE, "Hit an additional target within 30'"),
+    boon(
+        "Incapacitate",
+        3,
+        ONCE,
+        "Foe saves vs your Combat Skill ranks or is knocked out",
+    ),
+    boon("Knocked Prone", 3, ONCE, "Foe falls Prone, no save"),
+    boon("Multiple Strikes", 3, ONCE, "Hit an adjacent foe at -2"),
+    boon("Ricochet", 3, ONCE, "Bash hits a second target within 10' at -4"),
+    boon(
+        "Deadly Strike",
+        4,
+        ONCE,
+        "TSR vs Level: success is Incapacitated, failure dies in 10-X rounds",
+    ),
+    boon("Knocked Out", 4, ONCE, "Foe is Incapacitated, no save"),
+    boon(
+        "Death Strike",
+        5,
+        ONCE,
+        "TSR vs Level: failure dies, success is Incapacitated for 24 hours",
+    ),
+];
+
+/// Casting Boons
+pub const CASTING_BOONS: [Boon; 8] = [
+    boon("Attack Advantage", 1, STACKS, "+1 to the spell attack roll"),
+    boon("Harder Save", 1, STACKS, "-1 to the target's Save Roll"),
+    boon("Increased Targets", 1, STACKS, "+1 extra target"),
+    boon("Increased Range", 2, STACKS, "+1 range increment"),
+    boon("Increased Duration", 2, STACKS, "+1 duration increment"),
+    boon("Increased Radius", 2, STACKS, "+1 radius increment"),
+    boon("Increased Distance", 2, STACKS, "+1 effect distance increment"),
+    boon(
+        "Increase Numerical Effect",
+        2,
+        STACKS,
+        "+1 to a non-damage number",
+    ),
+];
+
+/// Boon table for a kind of roll
+pub fn boon_table(kind: RollKind) -> Option<&'static [Boon]> {
+    match kind {
+        RollKind::Skill => Some(&SKILL_BOONS),
+        RollKind::Attack => Some(&COMBAT_BOONS),
+        RollKind::Cast => Some(&CASTING_BOONS),
+        RollKind::Save => None,
+    }
+}
+
+/// Success Levels being spent on one table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoonSpend {
+    pub kind: RollKind,
+    pub table: &'static [Boon],
+    /// Success Levels to spend
+    pub success_levels: u8,
+    /// Instances bought, by table index, in purchase order
+    pub bought: Vec<(usize, u8)>,
+}
+
+impl BoonSpend {
+    /// Start spending `success_levels` on the table for `kind`
+    ///
+    /// Returns None if there's nothing to spend or no table to spend it on.
+    pub fn new(kind: RollKind, success_levels: u8) -> Option<Self> {
+        let table = boon_table(kind)?;
+        (success_levels > 0).then(|| BoonSpend {
+            kind,
+            table,
+            success_levels,
+            bought: Vec::new(),
+        })
+    }
+
+    /// SL spent so far
+    pub fn spent(&self) -> u8 {
+        self.bought
+            .iter()
+            .map(|&(index, count)| self.table[index].cost + (count - 1))
+            .sum()
+    }
+
+    /// SL left to spend
+    pub fn remaining(&self) -> u8 {
+        self.success_levels - self.spent()
+    }
+
+    /// Instances of a boon bought so far
+    fn count(&self, index: usize) -> u8 {
+        self.bought
+            .iter()
+            .find(|&&(i, _)| i == index)
+            .map(|&(_, count)| count)
+            .unwrap_or(0)
+    }
+
+    /// SL the next instance of a boon costs
+    pub fn next_cost(&self, index: usize) -> u8 {
+        if self.count(index) == 0 {
+            self.table[index].cost
+        } else {
+            1
+        }
+    }
+
+    /// Check a boon can be bought, or explain why not
+    pub fn check(&self, index: usize) -> Result<(), String> {
+        let boon = self.table.get(index).ok_or("⚠️ No such boon")?;
+        let count = self.count(index);
+        match boon.limit {
+            BoonLimit::Once if count > 0 => {
+                return Err(format!("⚠️ **{}** can only be bought once", boon.name));
+            }
+            BoonLimit::Exclusive => {
+                let other = self
+                    .bought
+                    .iter()
+                    .find(|&&(i, _)| self.table[i].limit == BoonLimit::Exclusive);
+                if let Some(&(i, _)) = other {
+                    return Err(format!(
+                        "⚠️ Only one `*` boon may apply at a time, and you have **{}**",
+                        self.table[i].name
+                    ));
+                }
+            }
+            BoonLimit::Cumulative { max: Some(max) } if count >= max => {
+                return Err(format!("⚠️ **{}** is capped at {} SL", boon.name, max));
+            }
+            _ => {}
+        }
+        if self.next_cost(index) > self.remaining() {
+            return Err(format!(
+                "⚠️ **{}** costs {} SL and you have {} left",
+                boon.name,
+                self.next_cost(index),
+                self.remaining()
+            ));
+        }
+        Ok(())
+    }
+
+    /// Buy one instance of a boon
+    pub fn buy(&mut self, index: usize) -> Result<(), String> {
+        self.check(index)?;
+        match self.bought.iter_mut().find(|(i, _)| *i == index) {
+            Some((_, count)) => *count += 1,
+            None => self.bought.push((index, 1)),
+        }
+        Ok(())
+    }
+
+    /// Table indices of the boons that can still be bought
+    pub fn affordable(&self) -> Vec<usize> {
+        (0..self.table.len()).filter(|&i| self.check(i).is_ok()).collect()
+    }
+
+    /// Format the purchases for Discord display
+    pub fn summary(&self) -> String {
+        let mut lines = vec![format!("🎁 **{} Boons**", self.kind.name())];
+        for &(index, count) in &self.bought {
+            let boon = &self.table[index];
+            let times = if count > 1 { format!(" x{}", count) } else { String::new() };
+            lines.push(format!(
+                "• **{}**{} ({} SL): {}",
+                boon.name,
+                times,
+                boon.cost + (count - 1),
+                boon.effect
+            ));
+        }
+        lines.push(format!(
+            "Spent {} of {} SL, **{} SL** left",
+            self.spent(),
+            self.success_levels,
+            self.remaining()
+        ));
+        lines.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index(table: &[Boon], name: &str) -> usize {
+        table.iter().position(|b| b.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_cumulative_boons_cost_one_more_sl() {
+        let mut spend = BoonSpend::new(RollKind::Cast, 4).unwrap();
+        let range = index(&CASTING_BOONS, "Increased Range");
+        spend.buy(range).unwrap();
+        assert_eq!(spend.remaining(), 2);
+        spend.buy(range).unwrap();
+        spend.buy(range).unwrap();
+        assert_eq!(spend.remaining(), 0);
+        assert!(spend.buy(range).is_err());
+        assert!(spend.summary().contains("x3 (4 SL)"));
+    }
+
+    #[test]
+    fn test_only_one_exclusive_boon() {
+        let mut spend = BoonSpend::new(RollKind::Skill, 5).unwrap();
+        spend.buy(index(&SKILL_BOONS, "Head Start")).unwrap();
+        assert!(spend.buy(index(&SKILL_BOONS, "Quietly")).is_err());
+        assert!(spend.buy(index(&SKILL_BOONS, "Extra Information")).is_ok());
+        assert!(spend.buy(index(&SKILL_BOONS, "Extra Information")).is_err());
+        let affordable = spend.affordable();
+        assert!(!affordable.contains(&index(&SKILL_BOONS, "Lucky Break")));
+        assert!(affordable.contains(&index(&SKILL_BOONS, "Useful Information")));
+    }
+
+    #[test]
+    fn test_capped_and_unaffordable() {
+        let mut spend = BoonSpend::new(RollKind::Skill, 5).unwrap();
+        let better = index(&SKILL_BOONS, "Better Result");
+        for _ in 0..5 {
+            spend.buy(better).unwrap();
+        }
+        assert_eq!(spend.remaining(), 0);
+        assert!(spend.affordable().is_empty());
+
+        let spend = BoonSpend::new(RollKind::Attack, 2).unwrap();
+        assert!(spend.check(index(&COMBAT_BOONS, "Knocked Out")).is_err());
+        assert!(BoonSpend::new(RollKind::Save, 3).is_none());
+        assert!(BoonSpend::new(RollKind::Skill, 0).is_none());
+    }
+}
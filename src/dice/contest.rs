@@ -0,0 +1,201 @@
+//! Conflicting actions (opposed rolls) for Fantasy Express
+//!
+//! Used when actions are mutually exclusive: contests, chases, hiding vs
+//! seeking. Every participant rolls the appropriate Skill and the highest
+//! result wins. A Critical Failure fails outright and suffers a Fumble, so
+//! fumblers rank last whatever their total. A tie for first means neither
+//! wins: find another solution or re-roll.
+
+use super::feat::{resolve_feat, FeatResult};
+use super::roll::{format_roll, roll_with_modifier, DiceResult, DEFAULT_FUMBLE_RANGE};
+use super::source::DiceSource;
+
+/// Most participants a single contest can hold
+pub const MAX_CONTESTANTS: usize = 20;
+
+/// Someone taking part in a contest (or helping with a roll), with their bonus
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Contestant {
+    pub name: String,
+    pub bonus: i32,
+}
+
+/// Parse names and bonuses written as `Name:+bonus`, separated by commas
+///
+/// e.g. "Thief:+12, Guard:+8, Dog:+10"
+pub fn parse_named_bonuses(input: &str) -> Result<Vec<Contestant>, String> {
+    let mut contestants = Vec::new();
+    for entry in input.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let invalid = || format!("⚠️ Couldn't read `{}`: use `Name:+bonus`", entry);
+        let (name, bonus) = entry.rsplit_once(':').ok_or_else(invalid)?;
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(invalid());
+        }
+        let bonus = bonus.trim().trim_start_matches('+').parse::<i32>().map_err(|_| invalid())?;
+        contestants.push(Contestant {
+            name: name.to_string(),
+            bonus,
+        });
+    }
+    Ok(contestants)
+}
+
+/// Parse contest participants written as `Name:+bonus`, separated by commas
+pub fn parse_contestants(input: &str) -> Result<Vec<Contestant>, String> {
+    let contestants = parse_named_bonuses(input)?;
+    if contestants.len() < 2 {
+        return Err("⚠️ A contest needs at least two participants, e.g. `Thief:+12, Guard:+8`".to_string());
+    }
+    if contestants.len() > MAX_CONTESTANTS {
+        return Err(format!("⚠️ A contest can have at most {} participants", MAX_CONTESTANTS));
+    }
+    Ok(contestants)
+}
+
+/// One participant's roll in a contest
+#[derive(Debug, Clone)]
+pub struct ContestRoll {
+    pub contestant: Contestant,
+    pub dice: DiceResult,
+    pub total: i32,
+    pub feat_result: FeatResult,
+}
+
+impl ContestRoll {
+    /// Critical Failure: ranks last and suffers a Fumble
+    pub fn fumbled(&self) -> bool {
+        self.feat_result == FeatResult::CriticalFailure
+    }
+}
+
+/// Roll for a single contestant
+pub fn roll_contestant(dice: &mut dyn DiceSource, contestant: &Contestant) -> ContestRoll {
+    let (result, total) = roll_with_modifier(dice, contestant.bonus, DEFAULT_FUMBLE_RANGE);
+    ContestRoll {
+        contestant: contestant.clone(),
+        feat_result: resolve_feat(total, result.is_fumble),
+        dice: result,
+        total,
+    }
+}
+
+/// Roll for every contestant and rank the results, best first
+pub fn roll_contest(dice: &mut dyn DiceSource, contestants: &[Contestant]) -> Vec<ContestRoll> {
+    let mut rolls: Vec<ContestRoll> = contestants
+        .iter()
+        .map(|contestant| roll_contestant(dice, contestant))
+        .collect();
+    // Stable, so tied participants keep the order they were listed in
+    rolls.sort_by_key(|roll| (roll.fumbled(), -roll.total));
+    rolls
+}
+
+/// Participants sharing first place (empty if everyone fumbled)
+pub fn leaders(ranked: &[ContestRoll]) -> &[ContestRoll] {
+    let Some(best) = ranked.first().filter(|roll| !roll.fumbled()) else {
+        return &[];
+    };
+    let count = ranked
+        .iter()
+        .take_while(|roll| !roll.fumbled() && roll.total == best.total)
+        .count();
+    &ranked[..count]
+}
+
+/// Whether first place is tied and needs a re-roll
+pub fn is_tied(ranked: &[ContestRoll]) -> bool {
+    leaders(ranked).len() > 1
+}
+
+/// Format a ranked contest for Discord display
+pub fn format_contest(ranked: &[ContestRoll]) -> String {
+    let mut lines = Vec::new();
+    let mut place = 0;
+    for (i, roll) in ranked.iter().enumerate() {
+        let marker = if roll.fumbled() {
+            "💀".to_string()
+        } else {
+            // Tied totals share a place
+            if i == 0 || ranked[i - 1].total != roll.total {
+                place = i + 1;
+            }
+            format!("{}.", place)
+        };
+        let mut line = format!(
+            "{} **{}**: {} = **{}** {} {}",
+            marker,
+            roll.contestant.name,
+            format_roll(&roll.dice, Some(roll.contestant.bonus)),
+            roll.total,
+            roll.feat_result.emoji(),
+            roll.feat_result.name()
+        );
+        if roll.fumbled() {
+            line.push_str(" (fails and suffers a Fumble)");
+        }
+        lines.push(line);
+    }
+
+    let leaders = leaders(ranked);
+    let verdict = match leaders {
+        [] => "💀 **Everyone fumbled!** Nobody wins".to_string(),
+        [winner] => format!("🏆 **{} wins!**", winner.contestant.name),
+        tied => format!(
+            "⚔️ **Tie** between {}! Neither wins, re-roll or find another solution",
+            tied.iter()
+                .map(|roll| format!("**{}**", roll.contestant.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+
+    format!("{}\n━━━━━━━━━━━━━━\n{}", lines.join("\n"), verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dice::source::ScriptedSource;
+
+    fn contestants(input: &str) -> Vec<Contestant> {
+        parse_contestants(input).unwrap()
+    }
+
+    #[test]
+    fn test_parse_contestants() {
+        let parsed = contestants("Thief:+12, Guard Captain:8 , Dog:-1");
+        assert_eq!(parsed.len(), 3);
+        assert_eq!(parsed[1].name, "Guard Captain");
+        assert_eq!((parsed[0].bonus, parsed[1].bonus, parsed[2].bonus), (12, 8, -1));
+        assert!(parse_contestants("Thief:+12").is_err());
+        assert!(parse_contestants("Thief:+12, Guard").is_err());
+        assert!(parse_contestants(":+3, Guard:+2").is_err());
+    }
+
+    #[test]
+    fn test_ranking_puts_fumbles_last() {
+        // Thief 5+6+12 = 23, Guard fumbles (1+1), Dog 7+7+10 = 24
+        let mut dice = ScriptedSource::new(&[5, 6, 1, 1, 7, 7]);
+        let ranked = roll_contest(&mut dice, &contestants("Thief:+12, Guard:+30, Dog:+10"));
+        let names: Vec<&str> = ranked.iter().map(|r| r.contestant.name.as_str()).collect();
+        assert_eq!(names, ["Dog", "Thief", "Guard"]);
+        assert!(ranked[2].fumbled());
+        assert_eq!(leaders(&ranked).len(), 1);
+        assert!(!is_tied(&ranked));
+    }
+
+    #[test]
+    fn test_ties_and_all_fumbles() {
+        let mut dice = ScriptedSource::new(&[5, 5, 4, 4, 3, 3]);
+        let ranked = roll_contest(&mut dice, &contestants("A:+2, B:+4, C:+1"));
+        assert!(is_tied(&ranked));
+        assert_eq!(leaders(&ranked).len(), 2);
+        assert!(format_contest(&ranked).contains("**Tie** between **A**, **B**"));
+
+        let mut dice = ScriptedSource::new(&[1, 1, 1, 1]);
+        let ranked = roll_contest(&mut dice, &contestants("A:+2, B:+4"));
+        assert!(leaders(&ranked).is_empty());
+        assert!(!is_tied(&ranked));
+    }
+}
@@ -0,0 +1,229 @@
+//! Combat Boons critical damage table
+//!
+//! Each damage type has a critical for 1-5 Success Levels, giving extra Hits
+//! and lasting effects (Bleed, Dazed, Pain, Stun).
+
+use std::fmt;
+
+/// Damage type column of the critical table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageType {
+    Bash,
+    Slash,
+    Pierce,
+    MagicEnergy,
+    MartialArts,
+    ToothClaw,
+}
+
+impl DamageType {
+    /// All damage types, in table order
+    pub const ALL: [DamageType; 6] = [
+        DamageType::Bash,
+        DamageType::Slash,
+        DamageType::Pierce,
+        DamageType::MagicEnergy,
+        DamageType::MartialArts,
+        DamageType::ToothClaw,
+    ];
+
+    /// Parse a slash command choice value
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|t| t.choice() == value)
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            DamageType::Bash => "bash",
+            DamageType::Slash => "slash",
+            DamageType::Pierce => "pierce",
+            DamageType::MagicEnergy => "magic",
+            DamageType::MartialArts => "martial_arts",
+            DamageType::ToothClaw => "tooth_claw",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            DamageType::Bash => "Bash",
+            DamageType::Slash => "Slash",
+            DamageType::Pierce => "Pierce",
+            DamageType::MagicEnergy => "Magic/Energy",
+            DamageType::MartialArts => "Martial Arts",
+            DamageType::ToothClaw => "Tooth & Claw",
+        }
+    }
+}
+
+/// Lasting effect of a critical
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CriticalEffect {
+    /// Lose # Hit Points each Upkeep phase until healed/bandaged
+    Bleed(u8),
+    /// -4 to all actions for # rounds
+    Dazed(u8),
+    /// -# penalty to actions
+    Pain(u8),
+    /// Cannot take Full Actions, Attack, or Cast for # rounds
+    Stun(u8),
+}
+
+impl fmt::Display for CriticalEffect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CriticalEffect::Bleed(n) => write!(f, "Bleed({})", n),
+            CriticalEffect::Dazed(n) => write!(f, "Dazed({})", n),
+            CriticalEffect::Pain(n) => write!(f, "Pain({})", n),
+            CriticalEffect::Stun(n) => write!(f, "Stun({})", n),
+        }
+    }
+}
+
+/// One cell of the critical table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Critical {
+    /// Base Success Level cost
+    pub sl_cost: u8,
+    /// Extra Hits dealt
+    pub extra_hits: i32,
+    /// Lasting effects on the target
+    pub effects: &'static [CriticalEffect],
+}
+
+impl fmt::Display for Critical {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "+{} Hits", self.extra_hits)?;
+        for effect in self.effects {
+            write!(f, ", {}", effect)?;
+        }
+        Ok(())
+    }
+}
+
+use CriticalEffect::{Bleed, Dazed, Pain, Stun};
+
+const fn crit(sl_cost: u8, extra_hits: i32, effects: &'static [CriticalEffect]) -> Critical {
+    Critical {
+        sl_cost,
+        extra_hits,
+        effects,
+    }
+}
+
+const BASH: [Critical; 5] = [
+    crit(1, 6, &[Dazed(1)]),
+    crit(2, 9, &[Dazed(2), Stun(1)]),
+    crit(3, 11, &[Pain(1), Bleed(1)]),
+    crit(4, 16, &[Stun(2), Pain(2)]),
+    crit(5, 20, &[Stun(3), Bleed(3)]),
+];
+
+const SLASH: [Critical; 5] = [
+    crit(1, 3, &[Pain(1)]),
+    crit(2, 6, &[Dazed(1), Pain(2)]),
+    crit(3, 9, &[Bleed(1), Pain(2)]),
+    crit(4, 12, &[Pain(3), Bleed(3)]),
+    crit(5, 15, &[Stun(1), Bleed(4)]),
+];
+
+const PIERCE: [Critical; 5] = [
+    crit(1, 2, &[Bleed(1)]),
+    crit(2, 4, &[Dazed(1), Pain(1)]),
+    crit(3, 6, &[Stun(1), Bleed(2)]),
+    crit(4, 8, &[Pain(2), Bleed(4)]),
+    crit(5, 10, &[Pain(3), Bleed(5)]),
+];
+
+const MAGIC_ENERGY: [Critical; 5] = [
+    crit(1, 4, &[Dazed(1)]),
+    crit(2, 7, &[Dazed(2), Pain(1)]),
+    crit(3, 10, &[Pain(2), Bleed(1)]),
+    crit(4, 14, &[Stun(2), Pain(3)]),
+    crit(5, 18, &[Stun(3), Pain(4)]),
+];
+
+const MARTIAL_ARTS: [Critical; 5] = [
+    crit(1, 3, &[Dazed(1)]),
+    crit(2, 5, &[Dazed(2), Pain(1)]),
+    crit(3, 8, &[Stun(1), Bleed(1)]),
+    crit(4, 11, &[Stun(3), Pain(2)]),
+    crit(5, 15, &[Stun(4), Pain(3)]),
+];
+
+const TOOTH_CLAW: [Critical; 5] = [
+    crit(1, 5, &[Dazed(1)]),
+    crit(2, 8, &[Pain(1), Bleed(1)]),
+    crit(3, 10, &[Stun(1), Bleed(2)]),
+    crit(4, 15, &[Pain(2), Bleed(3)]),
+    crit(5, 20, &[Stun(2), Bleed(4)]),
+];
+
+/// All criticals for a damage type, from 1 SL to 5 SL
+pub fn critical_table(damage_type: DamageType) -> &'static [Critical; 5] {
+    match damage_type {
+        DamageType::Bash => &BASH,
+        DamageType::Slash => &SLASH,
+        DamageType::Pierce => &PIERCE,
+        DamageType::MagicEnergy => &MAGIC_ENERGY,
+        DamageType::MartialArts => &MARTIAL_ARTS,
+        DamageType::ToothClaw => &TOOTH_CLAW,
+    }
+}
+
+/// Criticals a given number of Success Levels can buy
+pub fn affordable_criticals(damage_type: DamageType, success_levels: u8) -> &'static [Critical] {
+    let count = (success_levels as usize).min(5);
+    &critical_table(damage_type)[..count]
+}
+
+/// Format the criticals available for an attack
+pub fn format_criticals(damage_type: DamageType, success_levels: u8) -> String {
+    let mut output = format!(
+        "🩸 **{} Criticals** ({} SL available)",
+        damage_type.name(),
+        success_levels
+    );
+    for critical in affordable_criticals(damage_type, success_levels) {
+        output.push_str(&format!("\n• {} SL: {}", critical.sl_cost, critical));
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_costs_match_rows() {
+        for damage_type in DamageType::ALL {
+            for (i, critical) in critical_table(damage_type).iter().enumerate() {
+                assert_eq!(critical.sl_cost as usize, i + 1);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        let critical = &critical_table(DamageType::Bash)[1];
+        assert_eq!(critical.extra_hits, 9);
+        assert_eq!(critical.effects, &[Dazed(2), Stun(1)]);
+        assert_eq!(critical.to_string(), "+9 Hits, Dazed(2), Stun(1)");
+    }
+
+    #[test]
+    fn test_affordable_criticals() {
+        assert!(affordable_criticals(DamageType::Slash, 0).is_empty());
+        assert_eq!(affordable_criticals(DamageType::Slash, 3).len(), 3);
+        assert_eq!(affordable_criticals(DamageType::Slash, 9).len(), 5);
+    }
+
+    #[test]
+    fn test_choice_round_trip() {
+        for damage_type in DamageType::ALL {
+            assert_eq!(DamageType::from_choice(damage_type.choice()), Some(damage_type));
+        }
+        assert_eq!(DamageType::from_choice("fire"), None);
+    }
+}
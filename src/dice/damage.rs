@@ -0,0 +1,222 @@
+//! Attack damage pipeline
+//!
+//! Follows the rules' damage steps:
+//! 1. Base Hit Damage = DR + 1 per point over 20 (capped at 3x DR)
+//! 2. Spend Success Levels (Pierce Armor boons)
+//! 3. Subtract the target's Armor Rating (AR)
+
+use super::feat::FeatResult;
+
+/// Pierce Armor combat boon bought with Success Levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArmorPiercing {
+    None,
+    /// 2 SL: halve enemy AR
+    Pierce,
+    /// 3 SL: quarter enemy AR
+    PierceII,
+    /// 4 SL: ignore AR completely
+    PierceTrue,
+}
+
+impl ArmorPiercing {
+    /// All Pierce Armor boons, cheapest first
+    pub const ALL: [ArmorPiercing; 4] = [
+        ArmorPiercing::None,
+        ArmorPiercing::Pierce,
+        ArmorPiercing::PierceII,
+        ArmorPiercing::PierceTrue,
+    ];
+
+    /// Parse a slash command choice value
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|p| p.choice() == value)
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            ArmorPiercing::None => "none",
+            ArmorPiercing::Pierce => "pierce",
+            ArmorPiercing::PierceII => "pierce_ii",
+            ArmorPiercing::PierceTrue => "pierce_true",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            ArmorPiercing::None => "None",
+            ArmorPiercing::Pierce => "Pierce Armor",
+            ArmorPiercing::PierceII => "Pierce Armor II",
+            ArmorPiercing::PierceTrue => "Pierce Armor True",
+        }
+    }
+
+    /// Success Levels needed to buy this boon
+    pub fn sl_cost(&self) -> u8 {
+        match self {
+            ArmorPiercing::None => 0,
+            ArmorPiercing::Pierce => 2,
+            ArmorPiercing::PierceII => 3,
+            ArmorPiercing::PierceTrue => 4,
+        }
+    }
+
+    /// Armor Rating left after piercing (fractions round down)
+    pub fn apply(&self, armor_rating: i32) -> i32 {
+        match self {
+            ArmorPiercing::None => armor_rating,
+            ArmorPiercing::Pierce => armor_rating / 2,
+            ArmorPiercing::PierceII => armor_rating / 4,
+            ArmorPiercing::PierceTrue => 0,
+        }
+    }
+}
+
+/// Damage dealt by a hit, step by step
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttackDamage {
+    /// Weapon Damage Rating
+    pub damage_rating: i32,
+    /// Extra Hits from points over 20 (capped at 2x DR)
+    pub bonus_hits: i32,
+    /// Target's Armor Rating before piercing
+    pub armor_rating: i32,
+    /// Pierce Armor boon actually applied
+    pub piercing: ArmorPiercing,
+    /// Armor Rating after piercing
+    pub effective_ar: i32,
+    /// Success Levels spent in the pipeline
+    pub sl_spent: u8,
+    /// Final Hits applied to the target
+    pub hits: i32,
+}
+
+/// Run the damage pipeline for an attack result
+///
+/// Returns `None` when the attack does no damage (Failure or Critical Failure).
+/// A Partial Success deals DR reduced by AR; a Success adds points over 20 and
+/// may spend SL on `piercing`. Piercing the attack can't afford is not applied.
+pub fn resolve_damage(
+    feat_result: &FeatResult,
+    total: i32,
+    damage_rating: i32,
+    armor_rating: i32,
+    piercing: ArmorPiercing,
+) -> Option<AttackDamage> {
+    let (bonus_hits, piercing) = match feat_result {
+        FeatResult::CriticalFailure | FeatResult::Failure => return None,
+        FeatResult::PartialSuccess => (0, ArmorPiercing::None),
+        FeatResult::Success { .. } | FeatResult::CriticalSuccess => {
+            let bonus_hits = (total - 20).clamp(0, damage_rating * 2);
+            let piercing = if piercing.sl_cost() <= feat_result.success_levels() {
+                piercing
+            } else {
+                ArmorPiercing::None
+            };
+            (bonus_hits, piercing)
+        }
+    };
+
+    let effective_ar = piercing.apply(armor_rating);
+    let hits = (damage_rating + bonus_hits - effective_ar).max(0);
+
+    Some(AttackDamage {
+        damage_rating,
+        bonus_hits,
+        armor_rating,
+        piercing,
+        effective_ar,
+        sl_spent: piercing.sl_cost(),
+        hits,
+    })
+}
+
+/// Format the damage breakdown for Discord display
+pub fn format_damage(damage: &AttackDamage) -> String {
+    let mut output = format!(
+        "💥 **Damage: {} Hits** (DR {} + {} bonus",
+        damage.hits, damage.damage_rating, damage.bonus_hits
+    );
+
+    if damage.armor_rating > 0 {
+        if damage.piercing == ArmorPiercing::None {
+            output.push_str(&format!(" - AR {}", damage.armor_rating));
+        } else {
+            output.push_str(&format!(
+                " - AR {} ({}: AR {} → {}, {} SL)",
+                damage.effective_ar,
+                damage.piercing.name(),
+                damage.armor_rating,
+                damage.effective_ar,
+                damage.sl_spent
+            ));
+        }
+    }
+    output.push(')');
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_damage_on_failure() {
+        assert_eq!(resolve_damage(&FeatResult::Failure, 12, 8, 2, ArmorPiercing::None), None);
+        assert_eq!(
+            resolve_damage(&FeatResult::CriticalFailure, 40, 8, 2, ArmorPiercing::None),
+            None
+        );
+    }
+
+    #[test]
+    fn test_partial_success_deals_dr_minus_ar() {
+        let damage =
+            resolve_damage(&FeatResult::PartialSuccess, 18, 8, 3, ArmorPiercing::Pierce).unwrap();
+        assert_eq!(damage.bonus_hits, 0);
+        assert_eq!(damage.piercing, ArmorPiercing::None);
+        assert_eq!(damage.hits, 5);
+    }
+
+    #[test]
+    fn test_success_bonus_capped_at_triple_dr() {
+        let feat = FeatResult::CriticalSuccess;
+        let damage = resolve_damage(&feat, 60, 5, 0, ArmorPiercing::None).unwrap();
+        assert_eq!(damage.bonus_hits, 10);
+        assert_eq!(damage.hits, 15);
+    }
+
+    #[test]
+    fn test_piercing() {
+        let feat = FeatResult::Success { success_levels: 4 };
+        let halved = resolve_damage(&feat, 31, 10, 9, ArmorPiercing::Pierce).unwrap();
+        assert_eq!(halved.effective_ar, 4);
+        assert_eq!(halved.hits, 10 + 11 - 4);
+        assert_eq!(halved.sl_spent, 2);
+
+        let quartered = resolve_damage(&feat, 31, 10, 9, ArmorPiercing::PierceII).unwrap();
+        assert_eq!(quartered.effective_ar, 2);
+
+        let ignored = resolve_damage(&feat, 31, 10, 9, ArmorPiercing::PierceTrue).unwrap();
+        assert_eq!(ignored.effective_ar, 0);
+        assert_eq!(ignored.hits, 21);
+    }
+
+    #[test]
+    fn test_unaffordable_piercing_not_applied() {
+        let feat = FeatResult::Success { success_levels: 2 };
+        let damage = resolve_damage(&feat, 24, 6, 6, ArmorPiercing::PierceII).unwrap();
+        assert_eq!(damage.piercing, ArmorPiercing::None);
+        assert_eq!(damage.sl_spent, 0);
+        assert_eq!(damage.hits, 4);
+    }
+
+    #[test]
+    fn test_armor_cannot_heal() {
+        let damage =
+            resolve_damage(&FeatResult::PartialSuccess, 17, 3, 10, ArmorPiercing::None).unwrap();
+        assert_eq!(damage.hits, 0);
+    }
+}
@@ -0,0 +1,240 @@
+//! Defensive Modifier (DM) for Fantasy Express
+//!
+//! DM = Dexterity - Armor Dexterity penalty + Armor Quality + Shield + Magic
+//!    + Condition bonuses (Cover, etc.) +/- Parrying
+//!
+//! Held defenders take -6 DM, Prone defenders have their DM halved (but get
+//! +4 against ranged attacks instead), and Surprised or On Rear defenders get
+//! no Shield bonus.
+
+/// Cover between the attacker and the defender
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Cover {
+    #[default]
+    None,
+    /// Up to 50% hidden behind soft cover (+2)
+    HalfSoft,
+    /// Up to 50% hidden behind hard cover (+4)
+    HalfHard,
+    /// 50-90% hidden behind soft cover (+4)
+    FullSoft,
+    /// 50-90% hidden behind hard cover (+10)
+    FullHard,
+}
+
+impl Cover {
+    /// All cover types, least first
+    pub const ALL: [Cover; 5] = [
+        Cover::None,
+        Cover::HalfSoft,
+        Cover::HalfHard,
+        Cover::FullSoft,
+        Cover::FullHard,
+    ];
+
+    /// Parse a slash command choice value
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.choice() == value)
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            Cover::None => "none",
+            Cover::HalfSoft => "half_soft",
+            Cover::HalfHard => "half_hard",
+            Cover::FullSoft => "full_soft",
+            Cover::FullHard => "full_hard",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Cover::None => "No cover",
+            Cover::HalfSoft => "Half soft cover",
+            Cover::HalfHard => "Half hard cover",
+            Cover::FullSoft => "Full soft cover",
+            Cover::FullHard => "Full hard cover",
+        }
+    }
+
+    /// DM bonus
+    pub fn modifier(&self) -> i32 {
+        match self {
+            Cover::None => 0,
+            Cover::HalfSoft => 2,
+            Cover::HalfHard => 4,
+            Cover::FullSoft => 4,
+            Cover::FullHard => 10,
+        }
+    }
+}
+
+/// Every component of a Defensive Modifier
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DefenseModifiers {
+    pub dexterity: i32,
+    /// Armor Dexterity penalty, subtracted from DM
+    pub armor_penalty: i32,
+    pub armor_quality: i32,
+    /// Shield DM bonus
+    pub shield: i32,
+    /// Magic item bonuses
+    pub magic: i32,
+    /// Other bonuses not covered above, such as Dim Light
+    pub other: i32,
+    pub cover: Cover,
+    /// DM gained (or lost) from Parrying
+    pub parry: i32,
+    /// Held (-6 DM)
+    pub held: bool,
+    /// Prone (half DM, or +4 vs ranged attacks)
+    pub prone: bool,
+    /// Defending against a ranged attack
+    pub ranged: bool,
+    /// Surprised or attacked On Rear (no Shield bonus)
+    pub no_shield: bool,
+}
+
+impl DefenseModifiers {
+    /// Each component that applies, with its value
+    pub fn itemize(&self) -> Vec<(String, i32)> {
+        let mut items = Vec::new();
+
+        if self.dexterity != 0 {
+            items.push(("Dexterity".to_string(), self.dexterity));
+        }
+        if self.armor_penalty != 0 {
+            items.push(("Armor penalty".to_string(), -self.armor_penalty));
+        }
+        if self.armor_quality != 0 {
+            items.push(("Armor quality".to_string(), self.armor_quality));
+        }
+        if self.shield != 0 {
+            if self.no_shield {
+                items.push(("Shield (lost)".to_string(), 0));
+            } else {
+                items.push(("Shield".to_string(), self.shield));
+            }
+        }
+        if self.magic != 0 {
+            items.push(("Magic".to_string(), self.magic));
+        }
+        if self.other != 0 {
+            items.push(("Other".to_string(), self.other));
+        }
+        if self.cover.modifier() != 0 {
+            items.push((self.cover.name().to_string(), self.cover.modifier()));
+        }
+        if self.parry != 0 {
+            items.push(("Parry".to_string(), self.parry));
+        }
+        if self.held {
+            items.push(("Held".to_string(), -6));
+        }
+        if self.prone {
+            let subtotal: i32 = items.iter().map(|(_, value)| value).sum();
+            let modifier = prone_modifier(subtotal, self.ranged);
+            if modifier != 0 {
+                let name = if self.ranged { "Prone vs ranged" } else { "Prone (half DM)" };
+                items.push((name.to_string(), modifier));
+            }
+        }
+
+        items
+    }
+
+    /// Total Defensive Modifier
+    pub fn total(&self) -> i32 {
+        self.itemize().iter().map(|(_, value)| value).sum()
+    }
+
+    /// Format the components for Discord display, e.g. "Dexterity +2 | Shield +3"
+    pub fn breakdown(&self) -> String {
+        let items = self.itemize();
+        if items.is_empty() {
+            return "—".to_string();
+        }
+        items
+            .iter()
+            .map(|(name, value)| format!("{} {:+}", name, value))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+}
+
+/// DM change for a Prone defender with `dm` before it
+///
+/// Half DM against melee (a negative DM isn't improved by lying down), +4
+/// against ranged attacks.
+pub fn prone_modifier(dm: i32, ranged: bool) -> i32 {
+    if ranged {
+        4
+    } else if dm > 0 {
+        -(dm - dm / 2)
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn defense() -> DefenseModifiers {
+        DefenseModifiers {
+            dexterity: 3,
+            armor_penalty: 2,
+            armor_quality: 1,
+            shield: 4,
+            magic: 2,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_dm_formula() {
+        assert_eq!(defense().total(), 3 - 2 + 1 + 4 + 2);
+        let covered = DefenseModifiers {
+            cover: Cover::FullHard,
+            parry: 5,
+            ..defense()
+        };
+        assert_eq!(covered.total(), 8 + 10 + 5);
+    }
+
+    #[test]
+    fn test_conditions() {
+        let held = DefenseModifiers {
+            held: true,
+            ..defense()
+        };
+        assert_eq!(held.total(), 8 - 6);
+
+        let surprised = DefenseModifiers {
+            no_shield: true,
+            ..defense()
+        };
+        assert_eq!(surprised.total(), 8 - 4);
+
+        // Half of 9 rounds down to 4
+        let prone = DefenseModifiers {
+            prone: true,
+            magic: 3,
+            ..defense()
+        };
+        assert_eq!(prone.total(), 4);
+        let prone_negative = DefenseModifiers {
+            dexterity: -2,
+            prone: true,
+            ..Default::default()
+        };
+        assert_eq!(prone_negative.total(), -2);
+        let prone_ranged = DefenseModifiers {
+            ranged: true,
+            ..prone
+        };
+        assert_eq!(prone_ranged.total(), 9 + 4);
+    }
+}
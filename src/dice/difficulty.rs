@@ -0,0 +1,118 @@
+//! Skill Roll difficulty for Fantasy Express
+//!
+//! Skill Roll = 2d10 (open-ended) + Skill Bonus + Modifiers - Difficulty
+//!
+//! With no hurry or stress, a character can Take the Time: +4 to the roll
+//! for at least double the normal time.
+
+/// Bonus for Taking the Time
+pub const TAKING_THE_TIME_BONUS: i32 = 4;
+
+/// Named difficulty levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Challenging,
+    Hard,
+    VeryHard,
+    Heroic,
+    Legendary,
+    Mythic,
+}
+
+impl Difficulty {
+    /// All difficulties, easiest first
+    pub const ALL: [Difficulty; 8] = [
+        Difficulty::Easy,
+        Difficulty::Normal,
+        Difficulty::Challenging,
+        Difficulty::Hard,
+        Difficulty::VeryHard,
+        Difficulty::Heroic,
+        Difficulty::Legendary,
+        Difficulty::Mythic,
+    ];
+
+    /// Parse a slash command choice value
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|d| d.choice() == value)
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Challenging => "challenging",
+            Difficulty::Hard => "hard",
+            Difficulty::VeryHard => "very_hard",
+            Difficulty::Heroic => "heroic",
+            Difficulty::Legendary => "legendary",
+            Difficulty::Mythic => "mythic",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Easy",
+            Difficulty::Normal => "Normal",
+            Difficulty::Challenging => "Challenging",
+            Difficulty::Hard => "Hard",
+            Difficulty::VeryHard => "Very Hard",
+            Difficulty::Heroic => "Heroic",
+            Difficulty::Legendary => "Legendary",
+            Difficulty::Mythic => "Mythic",
+        }
+    }
+
+    /// Modifier to the Skill Roll
+    pub fn modifier(&self) -> i32 {
+        match self {
+            Difficulty::Easy => 4,
+            Difficulty::Normal => 0,
+            Difficulty::Challenging => -3,
+            Difficulty::Hard => -6,
+            Difficulty::VeryHard => -9,
+            Difficulty::Heroic => -12,
+            Difficulty::Legendary => -15,
+            Difficulty::Mythic => -20,
+        }
+    }
+}
+
+/// Format the difficulty table for Discord display
+pub fn format_difficulty_table() -> String {
+    let mut output = String::from("| Difficulty | Modifier |\n|------------|----------|\n");
+    for difficulty in Difficulty::ALL {
+        output.push_str(&format!("| {} | {:+} |\n", difficulty.name(), difficulty.modifier()));
+    }
+    output.push_str(&format!(
+        "\n*Taking the Time: {:+} (double time)*",
+        TAKING_THE_TIME_BONUS
+    ));
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_modifiers() {
+        let modifiers: Vec<i32> = Difficulty::ALL.iter().map(|d| d.modifier()).collect();
+        assert_eq!(modifiers, [4, 0, -3, -6, -9, -12, -15, -20]);
+        assert_eq!(Difficulty::from_choice("very_hard"), Some(Difficulty::VeryHard));
+        assert_eq!(Difficulty::from_choice("impossible"), None);
+    }
+
+    #[test]
+    fn test_table() {
+        let table = format_difficulty_table();
+        assert!(table.contains("| Normal | +0 |"));
+        assert!(table.contains("| Mythic | -20 |"));
+        assert!(table.contains("Taking the Time: +4"));
+    }
+}
@@ -3,6 +3,27 @@
 //! All actions use the same target number: 20
 //! Results determine success levels and special outcomes
 
+/// Kind of action a FEAT roll resolves
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RollKind {
+    Skill,
+    Attack,
+    Save,
+    Cast,
+}
+
+impl RollKind {
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            RollKind::Skill => "Skill",
+            RollKind::Attack => "Attack",
+            RollKind::Save => "Save",
+            RollKind::Cast => "Casting",
+        }
+    }
+}
+
 /// Result of a FEAT check
 #[derive(Debug, Clone, PartialEq)]
 pub enum FeatResult {
@@ -20,7 +41,6 @@ pub enum FeatResult {
 
 impl FeatResult {
     /// Get the number of success levels
-    #[allow(dead_code)]
     pub fn success_levels(&self) -> u8 {
         match self {
             FeatResult::CriticalFailure => 0,
@@ -32,7 +52,6 @@ impl FeatResult {
     }
 
     /// Check if this is any kind of success
-    #[allow(dead_code)]
     pub fn is_success(&self) -> bool {
         matches!(
             self,
@@ -62,20 +81,55 @@ impl FeatResult {
         }
     }
 
-    /// Get description of the result
-    pub fn description(&self) -> &'static str {
-        match self {
-            FeatResult::CriticalFailure => {
+    /// Get description of the result for the kind of roll it resolves
+    ///
+    /// The same FEAT result means different things for a skill, an attack, a
+    /// save or a spell: a failed casting still spends its Mana, a Partial
+    /// Success save still fails, and so on.
+    pub fn description(&self, kind: RollKind) -> &'static str {
+        match (kind, self) {
+            (RollKind::Skill, FeatResult::CriticalFailure) => {
                 "Fumble! Danger, broken equipment, or +4 to enemies' next roll."
             }
-            FeatResult::Failure => "Task not accomplished.",
-            FeatResult::PartialSuccess => {
+            (RollKind::Skill, FeatResult::Failure) => "Task not accomplished.",
+            (RollKind::Skill, FeatResult::PartialSuccess) => {
                 "Bare minimum success with cost/complication, or failure."
             }
-            FeatResult::Success { .. } => "Task accomplished! Spend Success Levels on Boons.",
-            FeatResult::CriticalSuccess => {
+            (RollKind::Skill, FeatResult::Success { .. }) => {
+                "Task accomplished! Spend Success Levels on Boons."
+            }
+            (RollKind::Skill, FeatResult::CriticalSuccess) => {
                 "Best possible outcome! 5 Success Levels for Boons."
             }
+
+            (RollKind::Attack, FeatResult::CriticalFailure) => "The attack goes badly wrong: Weapon Fumble.",
+            (RollKind::Attack, FeatResult::Failure) => "A miss or a glancing blow: no damage.",
+            (RollKind::Attack, FeatResult::PartialSuccess) => "A grazing hit: DR only, reduced by AR.",
+            (RollKind::Attack, FeatResult::Success { .. }) => {
+                "A solid hit! Spend Success Levels on Combat Boons."
+            }
+            (RollKind::Attack, FeatResult::CriticalSuccess) => {
+                "A perfect hit, with an automatic Death Strike!"
+            }
+
+            (RollKind::Save, FeatResult::CriticalFailure) => "Fumble! The save fails whatever the total.",
+            (RollKind::Save, FeatResult::Failure) => "The effect takes hold.",
+            (RollKind::Save, FeatResult::PartialSuccess) => "Not quite: the save still fails.",
+            (RollKind::Save, FeatResult::Success { .. } | FeatResult::CriticalSuccess) => {
+                "The effect is resisted."
+            }
+
+            (RollKind::Cast, FeatResult::CriticalFailure) => "Spell Fumble! The Mana is lost.",
+            (RollKind::Cast, FeatResult::Failure) => "The spell fails, and the Mana is still spent.",
+            (RollKind::Cast, FeatResult::PartialSuccess) => {
+                "Choose: the spell fails with no Mana spent, works at half effect, or costs double Mana."
+            }
+            (RollKind::Cast, FeatResult::Success { .. }) => {
+                "The spell works! Spend Success Levels on Casting Boons."
+            }
+            (RollKind::Cast, FeatResult::CriticalSuccess) => {
+                "All of the spell's effects are doubled! 5 Success Levels for Casting Boons."
+            }
         }
     }
 }
@@ -115,13 +169,13 @@ pub fn resolve_feat(total: i32, is_fumble: bool) -> FeatResult {
 }
 
 /// Format a complete FEAT result for Discord display
-pub fn format_feat_result(result: &FeatResult, total: i32) -> String {
+pub fn format_feat_result(result: &FeatResult, kind: RollKind, total: i32) -> String {
     format!(
         "{} **{}** (Total: {})\n{}",
         result.emoji(),
         result.name(),
         total,
-        result.description()
+        result.description(kind)
     )
 }
 
@@ -220,4 +274,14 @@ mod tests {
         assert!(FeatResult::Success { success_levels: 1 }.is_success());
         assert!(FeatResult::CriticalSuccess.is_success());
     }
+
+    #[test]
+    fn test_descriptions_by_kind() {
+        let failure = FeatResult::Failure;
+        assert_eq!(failure.description(RollKind::Skill), "Task not accomplished.");
+        assert!(failure.description(RollKind::Cast).contains("Mana is still spent"));
+        assert!(FeatResult::PartialSuccess.description(RollKind::Save).contains("still fails"));
+        assert!(FeatResult::CriticalSuccess.description(RollKind::Cast).contains("doubled"));
+        assert!(format_feat_result(&failure, RollKind::Attack, 12).contains("(Total: 12)\nA miss"));
+    }
 }
@@ -0,0 +1,142 @@
+//! Weapon fumble table for Fantasy Express
+//!
+//! Fumble roll = 2d10 (not open-ended) + weapon modifier
+//! - 1 per 2 ranks in the weapon skill - 1 per Weapon Focus
+
+/// Column of the weapon fumble modifier table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WeaponClass {
+    MeleeThrown,
+    Missile,
+}
+
+impl WeaponClass {
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            WeaponClass::MeleeThrown => "Melee/Thrown",
+            WeaponClass::Missile => "Missile",
+        }
+    }
+}
+
+/// One row of the weapon fumble modifier table
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeaponFumbleCategory {
+    /// Slash command choice value
+    pub key: &'static str,
+    /// Display name
+    pub name: &'static str,
+    pub class: WeaponClass,
+    /// Fumble roll modifier
+    pub modifier: i32,
+}
+
+const fn category(
+    key: &'static str,
+    name: &'static str,
+    class: WeaponClass,
+    modifier: i32,
+) -> WeaponFumbleCategory {
+    WeaponFumbleCategory {
+        key,
+        name,
+        class,
+        modifier,
+    }
+}
+
+/// Weapon fumble modifiers, melee/thrown column first, then missile
+pub const WEAPON_FUMBLE_CATEGORIES: [WeaponFumbleCategory; 10] = [
+    category("brawl", "Brawl/Hand/Short impact", WeaponClass::MeleeThrown, 0),
+    category("short_edged", "Short edged/Long impact", WeaponClass::MeleeThrown, 2),
+    category("long_edged", "Long edged", WeaponClass::MeleeThrown, 4),
+    category("two_handed", "Two-handed/Chain", WeaponClass::MeleeThrown, 6),
+    category("polearm", "Polearm/Net/Whip", WeaponClass::MeleeThrown, 10),
+    category("light_crossbow", "Hand/Light crossbow", WeaponClass::Missile, 0),
+    category("short_bow", "Short bow/Sling", WeaponClass::Missile, 2),
+    category("heavy_crossbow", "Heavy crossbow", WeaponClass::Missile, 4),
+    category("composite_bow", "Composite bow", WeaponClass::Missile, 6),
+    category("long_bow", "Long bow", WeaponClass::Missile, 10),
+];
+
+/// Look up a weapon fumble category by its choice value
+pub fn weapon_category(key: &str) -> Option<&'static WeaponFumbleCategory> {
+    WEAPON_FUMBLE_CATEGORIES.iter().find(|c| c.key == key)
+}
+
+/// Everything that modifies a weapon fumble roll
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WeaponFumbleModifiers {
+    pub category: &'static WeaponFumbleCategory,
+    /// Ranks in the weapon skill (-1 per 2 ranks)
+    pub skill_ranks: i32,
+    /// Weapon Focus count (-1 each)
+    pub weapon_focus: i32,
+}
+
+impl WeaponFumbleModifiers {
+    /// Reduction from skill ranks
+    pub fn rank_reduction(&self) -> i32 {
+        self.skill_ranks / 2
+    }
+
+    /// Total modifier to the fumble roll
+    pub fn total(&self) -> i32 {
+        self.category.modifier - self.rank_reduction() - self.weapon_focus
+    }
+}
+
+/// Resolve a weapon fumble roll total on the fumble results table
+pub fn resolve_weapon_fumble(total: i32) -> &'static str {
+    match total {
+        t if t <= 15 => "📋 Make Assessment Roll next Upkeep",
+        16..=20 => "📋 Assessment + choose 1: Drop weapon OR Take 1 SL critical to self",
+        21..=24 => "📋 Assessment + choose 2: Drop, 2 SL critical to self, Hit ally",
+        25..=29 => "📋 Assessment + choose 2: Drop, 2 SL critical, Hit ally, Weapon breaks",
+        _ => "💀 Assessment + choose 2: Drop, 3 SL critical, Hit ally, Weapon breaks",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_both_columns_present() {
+        for class in [WeaponClass::MeleeThrown, WeaponClass::Missile] {
+            let modifiers: Vec<i32> = WEAPON_FUMBLE_CATEGORIES
+                .iter()
+                .filter(|c| c.class == class)
+                .map(|c| c.modifier)
+                .collect();
+            assert_eq!(modifiers, vec![0, 2, 4, 6, 10]);
+        }
+    }
+
+    #[test]
+    fn test_lookup() {
+        let long_bow = weapon_category("long_bow").unwrap();
+        assert_eq!(long_bow.class, WeaponClass::Missile);
+        assert_eq!(long_bow.modifier, 10);
+        assert!(weapon_category("trebuchet").is_none());
+    }
+
+    #[test]
+    fn test_modifier_total() {
+        let modifiers = WeaponFumbleModifiers {
+            category: weapon_category("composite_bow").unwrap(),
+            skill_ranks: 5,
+            weapon_focus: 1,
+        };
+        assert_eq!(modifiers.rank_reduction(), 2);
+        assert_eq!(modifiers.total(), 6 - 2 - 1);
+    }
+
+    #[test]
+    fn test_fumble_results() {
+        assert!(resolve_weapon_fumble(15).contains("Assessment Roll next Upkeep"));
+        assert!(resolve_weapon_fumble(20).contains("choose 1"));
+        assert!(resolve_weapon_fumble(30).contains("3 SL critical"));
+    }
+}
@@ -0,0 +1,118 @@
+//! Helping with a Skill Roll
+//!
+//! Each helper makes their own Skill Roll before the lead character, and the
+//! result feeds a bonus into the lead's roll:
+//!
+//! | Helper's result   | Bonus to the lead                    |
+//! |-------------------|--------------------------------------|
+//! | Critical Success  | +5                                   |
+//! | Success           | +1 per Success Level                 |
+//! | Partial Success   | +1, but the helper faces a complication |
+//! | Failure           | None, potential trouble              |
+
+use super::contest::{parse_named_bonuses, roll_contestant, ContestRoll, Contestant};
+use super::feat::FeatResult;
+use super::roll::format_roll;
+use super::source::DiceSource;
+
+/// Most helpers one roll can have
+pub const MAX_HELPERS: usize = 10;
+
+/// Bonus a helper's result gives the lead character
+pub fn help_bonus(result: &FeatResult) -> i32 {
+    match result {
+        FeatResult::CriticalSuccess => 5,
+        FeatResult::Success { success_levels } => *success_levels as i32,
+        FeatResult::PartialSuccess => 1,
+        FeatResult::Failure | FeatResult::CriticalFailure => 0,
+    }
+}
+
+/// Parse helpers written as `Name:+bonus`, separated by commas
+pub fn parse_helpers(input: &str) -> Result<Vec<Contestant>, String> {
+    let helpers = parse_named_bonuses(input)?;
+    if helpers.is_empty() {
+        return Err("⚠️ List helpers as `Name:+bonus`, e.g. `Bram:+8, Ilsa:+5`".to_string());
+    }
+    if helpers.len() > MAX_HELPERS {
+        return Err(format!("⚠️ A roll can have at most {} helpers", MAX_HELPERS));
+    }
+    Ok(helpers)
+}
+
+/// One helper's roll and the bonus it gives
+#[derive(Debug, Clone)]
+pub struct HelpRoll {
+    pub roll: ContestRoll,
+    pub bonus: i32,
+}
+
+/// Roll for each helper in turn
+pub fn roll_helpers(dice: &mut dyn DiceSource, helpers: &[Contestant]) -> Vec<HelpRoll> {
+    helpers
+        .iter()
+        .map(|helper| {
+            let roll = roll_contestant(dice, helper);
+            let bonus = help_bonus(&roll.feat_result);
+            HelpRoll { roll, bonus }
+        })
+        .collect()
+}
+
+/// Total bonus the helpers give the lead character
+pub fn total_help(rolls: &[HelpRoll]) -> i32 {
+    rolls.iter().map(|help| help.bonus).sum()
+}
+
+/// Format the helpers' rolls for Discord display
+pub fn format_help(rolls: &[HelpRoll]) -> String {
+    let mut lines = vec![String::from("🤝 **Helpers**")];
+    for help in rolls {
+        let roll = &help.roll;
+        let note = match roll.feat_result {
+            FeatResult::PartialSuccess => " (faces a complication)",
+            FeatResult::Failure | FeatResult::CriticalFailure => " (potential trouble)",
+            _ => "",
+        };
+        lines.push(format!(
+            "• **{}**: {} = **{}** {} {} → **{:+}**{}",
+            roll.contestant.name,
+            format_roll(&roll.dice, Some(roll.contestant.bonus)),
+            roll.total,
+            roll.feat_result.emoji(),
+            roll.feat_result.name(),
+            help.bonus,
+            note
+        ));
+    }
+    lines.push(format!("Help: **{:+}**", total_help(rolls)));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dice::source::ScriptedSource;
+
+    #[test]
+    fn test_help_bonus() {
+        assert_eq!(help_bonus(&FeatResult::CriticalSuccess), 5);
+        assert_eq!(help_bonus(&FeatResult::Success { success_levels: 3 }), 3);
+        assert_eq!(help_bonus(&FeatResult::PartialSuccess), 1);
+        assert_eq!(help_bonus(&FeatResult::Failure), 0);
+        assert_eq!(help_bonus(&FeatResult::CriticalFailure), 0);
+    }
+
+    #[test]
+    fn test_helpers_add_up() {
+        // Bram 8+8+8 = 24 (2 SL), Ilsa 5+5+7 = 17 (Partial), Wil fumbles
+        let helpers = parse_helpers("Bram:+8, Ilsa:+7, Wil:+20").unwrap();
+        let mut dice = ScriptedSource::new(&[8, 8, 5, 5, 1, 1]);
+        let rolls = roll_helpers(&mut dice, &helpers);
+        let bonuses: Vec<i32> = rolls.iter().map(|help| help.bonus).collect();
+        assert_eq!(bonuses, [2, 1, 0]);
+        assert_eq!(total_help(&rolls), 3);
+        assert!(format_help(&rolls).contains("faces a complication"));
+        assert!(parse_helpers(" , ").is_err());
+    }
+}
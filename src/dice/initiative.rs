@@ -0,0 +1,232 @@
+//! Initiative modifiers for Fantasy Express
+//!
+//! Initiative = 2d10 (NOT open-ended) + Dexterity + the modifiers below,
+//! summed from the documented condition table.
+
+/// Shield carried (-1 per size)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShieldSize {
+    #[default]
+    None,
+    Target,
+    Small,
+    Normal,
+    Large,
+    Tower,
+}
+
+impl ShieldSize {
+    /// All shield sizes, smallest first
+    pub const ALL: [ShieldSize; 6] = [
+        ShieldSize::None,
+        ShieldSize::Target,
+        ShieldSize::Small,
+        ShieldSize::Normal,
+        ShieldSize::Large,
+        ShieldSize::Tower,
+    ];
+
+    /// Parse a slash command choice value
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.choice() == value)
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            ShieldSize::None => "none",
+            ShieldSize::Target => "target",
+            ShieldSize::Small => "small",
+            ShieldSize::Normal => "normal",
+            ShieldSize::Large => "large",
+            ShieldSize::Tower => "tower",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            ShieldSize::None => "No shield",
+            ShieldSize::Target => "Target shield",
+            ShieldSize::Small => "Small shield",
+            ShieldSize::Normal => "Normal shield",
+            ShieldSize::Large => "Large shield",
+            ShieldSize::Tower => "Tower shield",
+        }
+    }
+
+    /// Initiative modifier
+    pub fn modifier(&self) -> i32 {
+        match self {
+            ShieldSize::None => 0,
+            ShieldSize::Target => -1,
+            ShieldSize::Small => -2,
+            ShieldSize::Normal => -3,
+            ShieldSize::Large => -4,
+            ShieldSize::Tower => -5,
+        }
+    }
+}
+
+/// Encumbrance level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Encumbrance {
+    #[default]
+    Light,
+    /// -5
+    Medium,
+    /// -10
+    Heavy,
+}
+
+impl Encumbrance {
+    /// All encumbrance levels, lightest first
+    pub const ALL: [Encumbrance; 3] = [Encumbrance::Light, Encumbrance::Medium, Encumbrance::Heavy];
+
+    /// Parse a slash command choice value
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|e| e.choice() == value)
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            Encumbrance::Light => "light",
+            Encumbrance::Medium => "medium",
+            Encumbrance::Heavy => "heavy",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Encumbrance::Light => "Lightly encumbered",
+            Encumbrance::Medium => "Medium encumbered",
+            Encumbrance::Heavy => "Heavily encumbered",
+        }
+    }
+
+    /// Initiative modifier
+    pub fn modifier(&self) -> i32 {
+        match self {
+            Encumbrance::Light => 0,
+            Encumbrance::Medium => -5,
+            Encumbrance::Heavy => -10,
+        }
+    }
+}
+
+/// Every documented Initiative modifier
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InitiativeModifiers {
+    /// Weapon not ready, first round (-10)
+    pub weapon_not_ready: bool,
+    /// Surprised, first round (-20)
+    pub surprised: bool,
+    /// Two weapon fighting (-5)
+    pub two_weapons: bool,
+    /// Weapon with the Heavy quality (-5)
+    pub heavy_weapon: bool,
+    pub shield: ShieldSize,
+    pub encumbrance: Encumbrance,
+    /// Wounded more than 50% (-10)
+    pub wounded: bool,
+    /// Tier of the spell being cast (-1 per 2 Tiers, rounded up)
+    pub spell_tier: i32,
+    /// Weapon length steps in your favour (+2 per step)
+    pub weapon_length_steps: i32,
+    /// Longer weapon when closing to combat (+5)
+    pub closing: bool,
+}
+
+impl InitiativeModifiers {
+    /// Each modifier that applies, with its value
+    pub fn itemize(&self) -> Vec<(String, i32)> {
+        let mut items = Vec::new();
+
+        if self.weapon_not_ready {
+            items.push(("Weapon not ready".to_string(), -10));
+        }
+        if self.surprised {
+            items.push(("Surprised".to_string(), -20));
+        }
+        if self.two_weapons {
+            items.push(("Two weapon fighting".to_string(), -5));
+        }
+        if self.heavy_weapon {
+            items.push(("Heavy weapon".to_string(), -5));
+        }
+        if self.shield.modifier() != 0 {
+            items.push((self.shield.name().to_string(), self.shield.modifier()));
+        }
+        if self.encumbrance.modifier() != 0 {
+            items.push((self.encumbrance.name().to_string(), self.encumbrance.modifier()));
+        }
+        if self.wounded {
+            items.push(("Wounded >50%".to_string(), -10));
+        }
+        if self.spell_tier > 0 {
+            items.push((
+                format!("Casting Tier {} spell", self.spell_tier),
+                -((self.spell_tier + 1) / 2),
+            ));
+        }
+        if self.weapon_length_steps != 0 {
+            items.push((
+                format!("Weapon length ({} steps)", self.weapon_length_steps),
+                2 * self.weapon_length_steps,
+            ));
+        }
+        if self.closing {
+            items.push(("Longer weapon closing".to_string(), 5));
+        }
+
+        items
+    }
+
+    /// Total Initiative modifier
+    pub fn total(&self) -> i32 {
+        self.itemize().iter().map(|(_, modifier)| modifier).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_modifiers_by_default() {
+        let modifiers = InitiativeModifiers::default();
+        assert!(modifiers.itemize().is_empty());
+        assert_eq!(modifiers.total(), 0);
+    }
+
+    #[test]
+    fn test_spell_tier_rounds_up() {
+        for (tier, modifier) in [(1, -1), (2, -1), (3, -2), (6, -3), (7, -4)] {
+            let modifiers = InitiativeModifiers {
+                spell_tier: tier,
+                ..Default::default()
+            };
+            assert_eq!(modifiers.total(), modifier, "tier {}", tier);
+        }
+    }
+
+    #[test]
+    fn test_all_modifiers() {
+        let modifiers = InitiativeModifiers {
+            weapon_not_ready: true,
+            surprised: true,
+            two_weapons: true,
+            heavy_weapon: true,
+            shield: ShieldSize::Tower,
+            encumbrance: Encumbrance::Heavy,
+            wounded: true,
+            spell_tier: 3,
+            weapon_length_steps: 2,
+            closing: true,
+        };
+        assert_eq!(modifiers.itemize().len(), 10);
+        assert_eq!(modifiers.total(), -10 - 20 - 5 - 5 - 5 - 10 - 10 - 2 + 4 + 5);
+    }
+}
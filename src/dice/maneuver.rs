@@ -0,0 +1,407 @@
+//! Martial moves for Fantasy Express
+//!
+//! Most moves are Conflicting Actions between the attacker and the defender;
+//! what winning (or losing badly) does depends on the move. Riposte and
+//! Shield Bash are attacks against the defender's DM instead.
+//!
+//! | Move              | Action    | Roll                                |
+//! |-------------------|-----------|-------------------------------------|
+//! | Disarm            | Full      | AB vs foe's AB                      |
+//! | Feint             | Half      | Deceive vs Perception               |
+//! | Grapple           | Full      | Brawl vs Brawl (or RSR)             |
+//! | Knock Down        | Full      | Brawl vs Brawl (or RSR)             |
+//! | Lock              | Full      | AB (minus Parry) vs foe's AB        |
+//! | Maintain Distance | Full      | AB vs Athletics/Acrobatics          |
+//! | Riposte           | Full      | Parry first, then counterattack     |
+//! | Shield Bash       | Half      | Brawl -4 (or Blunt) vs DM, DR 2x shield DM |
+
+use super::contest::ContestRoll;
+use super::feat::FeatResult;
+
+/// Attack modifier for Shield Bashing with Brawl instead of a Blunt skill
+pub const SHIELD_BASH_BRAWL_PENALTY: i32 = -4;
+
+/// Attack modifier for Grappling your way out of a hold
+pub const GRAPPLE_ESCAPE_PENALTY: i32 = -6;
+
+/// A martial move
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Maneuver {
+    Disarm,
+    Feint,
+    Grapple,
+    KnockDown,
+    Lock,
+    MaintainDistance,
+    Riposte,
+    ShieldBash,
+}
+
+impl Maneuver {
+    /// All moves, in rules order
+    pub const ALL: [Maneuver; 8] = [
+        Maneuver::Disarm,
+        Maneuver::Feint,
+        Maneuver::Grapple,
+        Maneuver::KnockDown,
+        Maneuver::Lock,
+        Maneuver::MaintainDistance,
+        Maneuver::Riposte,
+        Maneuver::ShieldBash,
+    ];
+
+    /// Parse a slash command choice value
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|m| m.choice() == value)
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            Maneuver::Disarm => "disarm",
+            Maneuver::Feint => "feint",
+            Maneuver::Grapple => "grapple",
+            Maneuver::KnockDown => "knock_down",
+            Maneuver::Lock => "lock",
+            Maneuver::MaintainDistance => "maintain_distance",
+            Maneuver::Riposte => "riposte",
+            Maneuver::ShieldBash => "shield_bash",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Maneuver::Disarm => "Disarm",
+            Maneuver::Feint => "Feint",
+            Maneuver::Grapple => "Grapple",
+            Maneuver::KnockDown => "Knock Down",
+            Maneuver::Lock => "Lock",
+            Maneuver::MaintainDistance => "Maintain Distance",
+            Maneuver::Riposte => "Riposte",
+            Maneuver::ShieldBash => "Shield Bash",
+        }
+    }
+
+    /// Action the move takes
+    pub fn action(&self) -> &'static str {
+        match self {
+            Maneuver::Disarm | Maneuver::Lock => "Full Action (can still Parry)",
+            Maneuver::Feint => "Half Action + Half Action attack",
+            Maneuver::ShieldBash => "Half Action",
+            _ => "Full Action",
+        }
+    }
+
+    /// What each side rolls
+    pub fn resolution(&self) -> &'static str {
+        match self {
+            Maneuver::Disarm => "AB vs foe's AB",
+            Maneuver::Feint => "Deceive vs Perception",
+            Maneuver::Grapple | Maneuver::KnockDown => "Brawl vs Brawl (or RSR)",
+            Maneuver::Lock => "AB (minus Parry) vs foe's AB",
+            Maneuver::MaintainDistance => "AB vs Athletics/Acrobatics",
+            Maneuver::Riposte => "Counterattack vs DM after Parrying",
+            Maneuver::ShieldBash => "Brawl -4 or Blunt vs DM",
+        }
+    }
+
+    /// Whether the move is a Conflicting Action rather than an attack vs DM
+    pub fn is_opposed(&self) -> bool {
+        !matches!(self, Maneuver::Riposte | Maneuver::ShieldBash)
+    }
+}
+
+/// How well the foe's attack went before a Riposte
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RiposteOpening {
+    /// The foe did no damage (+4)
+    #[default]
+    NoDamage,
+    /// The foe did Hits only, no critical (+2)
+    HitsOnly,
+    /// The foe landed a critical (no bonus)
+    Critical,
+}
+
+impl RiposteOpening {
+    /// All openings, best first
+    pub const ALL: [RiposteOpening; 3] = [
+        RiposteOpening::NoDamage,
+        RiposteOpening::HitsOnly,
+        RiposteOpening::Critical,
+    ];
+
+    /// Parse a slash command choice value
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|o| o.choice() == value)
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            RiposteOpening::NoDamage => "no_damage",
+            RiposteOpening::HitsOnly => "hits_only",
+            RiposteOpening::Critical => "critical",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            RiposteOpening::NoDamage => "Foe did no damage",
+            RiposteOpening::HitsOnly => "Foe did Hits only",
+            RiposteOpening::Critical => "Foe landed a critical",
+        }
+    }
+
+    /// Counterattack modifier
+    pub fn modifier(&self) -> i32 {
+        match self {
+            RiposteOpening::NoDamage => 4,
+            RiposteOpening::HitsOnly => 2,
+            RiposteOpening::Critical => 0,
+        }
+    }
+}
+
+/// Everything that changes the attacker's roll for a move
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ManeuverSetup {
+    pub maneuver: Maneuver,
+    /// AB spent Parrying, taken off a Lock
+    pub parry: i32,
+    /// Grappling to escape a hold
+    pub escape: bool,
+    pub opening: RiposteOpening,
+    /// Shield Bashing with a Blunt skill rather than Brawl
+    pub blunt_skill: bool,
+}
+
+impl ManeuverSetup {
+    /// Move-specific modifiers to the attacker's roll
+    pub fn itemize(&self) -> Vec<(String, i32)> {
+        let mut items = Vec::new();
+        match self.maneuver {
+            Maneuver::Lock if self.parry != 0 => items.push(("Parry".to_string(), -self.parry)),
+            Maneuver::Grapple if self.escape => {
+                items.push(("Escaping a hold".to_string(), GRAPPLE_ESCAPE_PENALTY))
+            }
+            Maneuver::Riposte if self.opening.modifier() != 0 => {
+                items.push((self.opening.name().to_string(), self.opening.modifier()))
+            }
+            Maneuver::ShieldBash if !self.blunt_skill => {
+                items.push(("Brawl".to_string(), SHIELD_BASH_BRAWL_PENALTY))
+            }
+            _ => {}
+        }
+        items
+    }
+
+    /// Total move-specific modifier
+    pub fn total(&self) -> i32 {
+        self.itemize().iter().map(|(_, modifier)| modifier).sum()
+    }
+}
+
+/// Damage Rating of a Shield Bash
+pub fn shield_bash_damage_rating(shield_dm: i32) -> i32 {
+    shield_dm * 2
+}
+
+/// How the attacker fared in a Conflicting Action
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManeuverResult {
+    /// Critical Failure: the move fails and the attacker suffers a Fumble
+    Fumbled,
+    Lost,
+    Tied,
+    Won,
+    /// Won with a Critical Success
+    CriticalWin,
+    /// Won because the defender fumbled, so there's no margin to measure
+    FoeFumbled,
+}
+
+/// Judge the attacker's roll against the defender's
+pub fn judge(attacker: &ContestRoll, defender: &ContestRoll) -> ManeuverResult {
+    if attacker.fumbled() {
+        ManeuverResult::Fumbled
+    } else if defender.fumbled() {
+        ManeuverResult::FoeFumbled
+    } else if attacker.total < defender.total {
+        ManeuverResult::Lost
+    } else if attacker.total == defender.total {
+        ManeuverResult::Tied
+    } else if attacker.feat_result == FeatResult::CriticalSuccess {
+        ManeuverResult::CriticalWin
+    } else {
+        ManeuverResult::Won
+    }
+}
+
+/// What a Conflicting Action move does, given the result and the difference
+/// between the two rolls
+///
+/// `defender_critical` is whether the defender rolled a Critical Success,
+/// which makes a Disarm backfire. A fumbling defender's total means nothing,
+/// so `FoeFumbled` gives the plain win without any margin-based bonus.
+pub fn maneuver_effect(
+    maneuver: Maneuver,
+    result: ManeuverResult,
+    difference: i32,
+    escape: bool,
+    defender_critical: bool,
+) -> String {
+    use ManeuverResult::*;
+
+    if result == Fumbled {
+        return format!(
+            "💀 The {} fails and you suffer a Fumble: roll `/fumble`",
+            maneuver.name()
+        );
+    }
+    if result == FoeFumbled {
+        let win = match maneuver {
+            Maneuver::Feint => "🎭 The foe bites, but a fumbled roll gives no margin for an AB bonus".to_string(),
+            _ => maneuver_effect(maneuver, Won, 0, escape, false),
+        };
+        return format!("{}\n💀 The foe fumbles: roll `/fumble` for them", win);
+    }
+
+    match (maneuver, result) {
+        (Maneuver::Disarm, Won) => "🗡️ Foe disarmed: their weapon flies 1-10'".to_string(),
+        (Maneuver::Disarm, CriticalWin) => {
+            "🗡️ Foe disarmed: grab their weapon, or it flies 6-15'".to_string()
+        }
+        (Maneuver::Disarm, Lost) if defender_critical => {
+            "💀 Backfire! The foe's Critical Success makes you fumble your weapon: roll `/fumble`"
+                .to_string()
+        }
+        (Maneuver::Disarm, _) => "The foe keeps their weapon".to_string(),
+
+        (Maneuver::Feint, Won | CriticalWin) => format!(
+            "🎭 The foe bites: +{} AB for the rest of the round",
+            difference
+        ),
+        (Maneuver::Feint, Lost) => format!(
+            "The foe sees through it: they gain +{} DM against you this round",
+            difference
+        ),
+
+        (Maneuver::Grapple, Won | CriticalWin) if escape => "🤸 You break free of the hold".to_string(),
+        (Maneuver::Grapple, _) if escape => {
+            "Still Held: try again next round, or a TSR (Brawn) vs their Brawl ranks".to_string()
+        }
+        (Maneuver::Grapple, Won) => "🤼 The target is Held".to_string(),
+        (Maneuver::Grapple, CriticalWin) => "🤼 The target is Held and Incapacitated".to_string(),
+        (Maneuver::Grapple, Lost) => "The target slips your grasp".to_string(),
+
+        (Maneuver::KnockDown, Won) => "🤕 The target is knocked Prone".to_string(),
+        (Maneuver::KnockDown, CriticalWin) => format!(
+            "🤕 The target is knocked Prone and Stunned for {} round{}",
+            difference,
+            if difference == 1 { "" } else { "s" }
+        ),
+        (Maneuver::KnockDown, Lost) => "The target keeps their feet".to_string(),
+
+        (Maneuver::Lock, Won | CriticalWin | Tied) => {
+            "🔒 Both combatants are Locked: neither can move, attack or Parry. \
+             Break free with a Conflicting Action each round, or drop the weapon"
+                .to_string()
+        }
+        (Maneuver::Lock, Lost) => "The foe's weapon slides free".to_string(),
+
+        (Maneuver::MaintainDistance, Won) => {
+            "🛡️ Foe held at bay: attack them at your AB -4".to_string()
+        }
+        (Maneuver::MaintainDistance, CriticalWin) => {
+            "🛡️ Foe held at bay: attack them at your full AB".to_string()
+        }
+        (Maneuver::MaintainDistance, Lost) => {
+            "The foe closes in and attacks, or Disengages".to_string()
+        }
+
+        (_, Tied) => "⚔️ Tie: neither wins, re-roll or find another solution".to_string(),
+        // Riposte and Shield Bash are attacks, not Conflicting Actions
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dice::contest::{roll_contestant, Contestant};
+    use crate::dice::source::ScriptedSource;
+
+    fn roll(bonus: i32, dice: &[u8]) -> ContestRoll {
+        let contestant = Contestant {
+            name: "Someone".to_string(),
+            bonus,
+        };
+        roll_contestant(&mut ScriptedSource::new(dice), &contestant)
+    }
+
+    #[test]
+    fn test_judge() {
+        assert_eq!(judge(&roll(10, &[6, 6]), &roll(5, &[6, 6])), ManeuverResult::Won);
+        assert_eq!(judge(&roll(5, &[6, 6]), &roll(5, &[6, 6])), ManeuverResult::Tied);
+        assert_eq!(judge(&roll(5, &[6, 6]), &roll(10, &[6, 6])), ManeuverResult::Lost);
+        assert_eq!(judge(&roll(25, &[6, 6]), &roll(10, &[6, 6])), ManeuverResult::CriticalWin);
+        // A fumbling defender loses whatever their total
+        assert_eq!(judge(&roll(0, &[3, 3]), &roll(50, &[1, 1])), ManeuverResult::FoeFumbled);
+        assert_eq!(judge(&roll(50, &[1, 1]), &roll(0, &[3, 3])), ManeuverResult::Fumbled);
+    }
+
+    #[test]
+    fn test_effects_use_difference() {
+        let feint = maneuver_effect(Maneuver::Feint, ManeuverResult::Won, 7, false, false);
+        assert!(feint.contains("+7 AB"));
+        let feint = maneuver_effect(Maneuver::Feint, ManeuverResult::Lost, 3, false, false);
+        assert!(feint.contains("+3 DM"));
+        let knock = maneuver_effect(Maneuver::KnockDown, ManeuverResult::CriticalWin, 4, false, false);
+        assert!(knock.contains("Stunned for 4 rounds"));
+        let lock = maneuver_effect(Maneuver::Lock, ManeuverResult::Tied, 0, false, false);
+        assert!(lock.contains("Both combatants are Locked"));
+    }
+
+    #[test]
+    fn test_foe_fumble_has_no_margin() {
+        // Attacker 4+4+2 = 10, defender fumbles 1+1+30 = 32
+        let attacker = roll(2, &[4, 4]);
+        let defender = roll(30, &[1, 1]);
+        assert!(attacker.total < defender.total);
+        assert_eq!(judge(&attacker, &defender), ManeuverResult::FoeFumbled);
+
+        let feint = maneuver_effect(Maneuver::Feint, ManeuverResult::FoeFumbled, 0, false, false);
+        assert!(!feint.contains("AB for the rest"));
+        assert!(feint.contains("`/fumble`"));
+        let knock = maneuver_effect(Maneuver::KnockDown, ManeuverResult::FoeFumbled, 0, false, false);
+        assert!(knock.contains("knocked Prone") && !knock.contains("Stunned"));
+    }
+
+    #[test]
+    fn test_disarm_backfire() {
+        let backfire = maneuver_effect(Maneuver::Disarm, ManeuverResult::Lost, 12, false, true);
+        assert!(backfire.contains("Backfire"));
+        let lost = maneuver_effect(Maneuver::Disarm, ManeuverResult::Lost, 12, false, false);
+        assert!(!lost.contains("Backfire"));
+    }
+
+    #[test]
+    fn test_setup_modifiers() {
+        let setup = |maneuver| ManeuverSetup {
+            maneuver,
+            parry: 3,
+            escape: true,
+            opening: RiposteOpening::HitsOnly,
+            blunt_skill: false,
+        };
+        assert_eq!(setup(Maneuver::Lock).total(), -3);
+        assert_eq!(setup(Maneuver::Grapple).total(), -6);
+        assert_eq!(setup(Maneuver::Riposte).total(), 2);
+        assert_eq!(setup(Maneuver::ShieldBash).total(), -4);
+        assert_eq!(setup(Maneuver::Disarm).total(), 0);
+        assert_eq!(shield_bash_damage_rating(3), 6);
+    }
+}
@@ -1,9 +1,28 @@
 //! Dice module for Fantasy Express RPG
 //!
-//! Provides core dice rolling mechanics and FEAT table resolution
+//! Provides core dice rolling mechanics, FEAT table resolution and exact odds
 
+pub mod asf;
+pub mod boons;
+pub mod contest;
+pub mod criticals;
+pub mod damage;
+pub mod defense;
+pub mod difficulty;
 pub mod feat;
+pub mod fumble;
+pub mod helping;
+pub mod initiative;
+pub mod maneuver;
+pub mod odds;
+pub mod parry;
+pub mod range;
+pub mod resonance;
 pub mod roll;
+pub mod save;
+pub mod source;
+pub mod spell_attack;
 
 pub use feat::resolve_feat;
-pub use roll::{roll_2d10_closed, roll_2d10_open, roll_with_modifier};
+pub use roll::{roll_2d10_closed, roll_2d10_open, roll_with_modifier, DEFAULT_FUMBLE_RANGE};
+pub use source::SeededSource;
@@ -0,0 +1,205 @@
+//! Exact probabilities for Fantasy Express rolls
+//!
+//! Computes the full distribution of a 2d10 open-ended roll (including
+//! chained explosions and the fumble) and maps it through the FEAT table.
+
+use std::collections::BTreeMap;
+
+use super::feat::{resolve_feat, FeatResult};
+use super::roll::DEFAULT_FUMBLE_RANGE;
+
+/// Default number of chained explosions followed before truncating
+pub const DEFAULT_EXPLOSION_DEPTH: u32 = 5;
+
+/// Probability distribution of a 2d10 open-ended roll
+#[derive(Debug, Clone)]
+pub struct RollDistribution {
+    /// Probability of each raw total for non-fumble rolls
+    pub totals: BTreeMap<i32, f64>,
+    /// Probability of a fumble (unmodified roll within the fumble range)
+    pub fumble: f64,
+}
+
+/// Number of ways two d10s can sum to `sum` (out of 100)
+fn pair_count(sum: i32) -> u32 {
+    if (2..=20).contains(&sum) {
+        (10 - (sum - 11).abs()) as u32
+    } else {
+        0
+    }
+}
+
+/// Distribution of one 2d10 roll that may explode up to `depth` more times
+fn chain_distribution(depth: u32) -> BTreeMap<i32, f64> {
+    let tail = if depth > 0 {
+        Some(chain_distribution(depth - 1))
+    } else {
+        None
+    };
+
+    let mut dist = BTreeMap::new();
+    for sum in 2..=20 {
+        let p = pair_count(sum) as f64 / 100.0;
+        match &tail {
+            Some(tail) if sum >= 19 => {
+                for (extra, q) in tail {
+                    *dist.entry(sum + extra).or_insert(0.0) += p * q;
+                }
+            }
+            _ => *dist.entry(sum).or_insert(0.0) += p,
+        }
+    }
+    dist
+}
+
+/// Exact distribution of `roll_2d10_open_with_fumble_range` totals
+///
+/// Explosions are followed for at most `max_depth` extra rolls; the last roll
+/// in a truncated chain is counted without exploding again. A depth of 0 gives
+/// a closed 2d10 roll. Unmodified rolls from 2 to `fumble_range` count as fumbles.
+pub fn open_roll_distribution(max_depth: u32, fumble_range: u8) -> RollDistribution {
+    let fumble_range = fumble_range.max(DEFAULT_FUMBLE_RANGE) as i32;
+    // Exploded totals start at 21, so everything up to the fumble range is an
+    // unmodified first roll
+    let mut fumbles = chain_distribution(max_depth);
+    let totals = fumbles.split_off(&(fumble_range + 1));
+    RollDistribution {
+        totals,
+        fumble: fumbles.values().sum(),
+    }
+}
+
+/// Probability of each FEAT outcome for a roll
+#[derive(Debug, Clone)]
+pub struct FeatOdds {
+    /// Each FEAT outcome with its probability, from worst to best
+    pub outcomes: Vec<(FeatResult, f64)>,
+}
+
+impl FeatOdds {
+    /// Probability of a specific outcome
+    #[allow(dead_code)]
+    pub fn probability(&self, result: &FeatResult) -> f64 {
+        self.outcomes
+            .iter()
+            .find(|(r, _)| r == result)
+            .map(|(_, p)| *p)
+            .unwrap_or(0.0)
+    }
+
+    /// Probability of earning at least `levels` Success Levels
+    pub fn at_least_sl(&self, levels: u8) -> f64 {
+        self.outcomes
+            .iter()
+            .filter(|(r, _)| r.is_success() && r.success_levels() >= levels)
+            .map(|(_, p)| p)
+            .sum()
+    }
+}
+
+/// Exact FEAT odds for a 2d10 open-ended roll plus `modifier`
+pub fn feat_odds(modifier: i32, max_depth: u32, fumble_range: u8) -> FeatOdds {
+    let mut outcomes = vec![
+        (FeatResult::CriticalFailure, 0.0),
+        (FeatResult::Failure, 0.0),
+        (FeatResult::PartialSuccess, 0.0),
+        (FeatResult::Success { success_levels: 1 }, 0.0),
+        (FeatResult::Success { success_levels: 2 }, 0.0),
+        (FeatResult::Success { success_levels: 3 }, 0.0),
+        (FeatResult::Success { success_levels: 4 }, 0.0),
+        (FeatResult::CriticalSuccess, 0.0),
+    ];
+
+    let distribution = open_roll_distribution(max_depth, fumble_range);
+    outcomes[0].1 = distribution.fumble;
+    for (raw_total, p) in &distribution.totals {
+        let result = resolve_feat(raw_total + modifier, false);
+        if let Some(entry) = outcomes.iter_mut().find(|(r, _)| *r == result) {
+            entry.1 += p;
+        }
+    }
+
+    FeatOdds { outcomes }
+}
+
+/// Format FEAT odds as a percentage table for Discord
+pub fn format_feat_odds(odds: &FeatOdds) -> String {
+    odds.outcomes
+        .iter()
+        .map(|(result, p)| {
+            let mut line = format!("`{:>6.2}%` {} {}", p * 100.0, result.emoji(), result.name());
+            let levels = result.success_levels();
+            if (2..5).contains(&levels) {
+                line.push_str(&format!(" — {}+ SL: {:.2}%", levels, odds.at_least_sl(levels) * 100.0));
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPSILON: f64 = 1e-9;
+
+    #[test]
+    fn test_distribution_sums_to_one() {
+        for depth in 0..=6 {
+            let dist = open_roll_distribution(depth, DEFAULT_FUMBLE_RANGE);
+            let total: f64 = dist.totals.values().sum::<f64>() + dist.fumble;
+            assert!((total - 1.0).abs() < EPSILON, "depth {} sums to {}", depth, total);
+        }
+    }
+
+    #[test]
+    fn test_fumble_mass() {
+        let dist = open_roll_distribution(DEFAULT_EXPLOSION_DEPTH, DEFAULT_FUMBLE_RANGE);
+        assert!((dist.fumble - 0.01).abs() < EPSILON);
+        assert!(!dist.totals.contains_key(&2));
+    }
+
+    #[test]
+    fn test_wider_fumble_range() {
+        // 2-4 fumbles: 1 + 2 + 3 = 6 ways out of 100
+        let dist = open_roll_distribution(DEFAULT_EXPLOSION_DEPTH, 4);
+        assert!((dist.fumble - 0.06).abs() < EPSILON);
+        assert!(!dist.totals.contains_key(&4));
+        let total: f64 = dist.totals.values().sum::<f64>() + dist.fumble;
+        assert!((total - 1.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_closed_roll_odds() {
+        // Depth 0: 3-15 = 84 ways, 16-19 = 14 ways, 20 = 1 way
+        let odds = feat_odds(0, 0, DEFAULT_FUMBLE_RANGE);
+        assert!((odds.probability(&FeatResult::CriticalFailure) - 0.01).abs() < EPSILON);
+        assert!((odds.probability(&FeatResult::Failure) - 0.84).abs() < EPSILON);
+        assert!((odds.probability(&FeatResult::PartialSuccess) - 0.14).abs() < EPSILON);
+        assert!(
+            (odds.probability(&FeatResult::Success { success_levels: 1 }) - 0.01).abs() < EPSILON
+        );
+    }
+
+    #[test]
+    fn test_explosions_leave_partial_range() {
+        // With explosions, 19 always climbs to 21+ so only 16-18 stay partial
+        let odds = feat_odds(0, 1, DEFAULT_FUMBLE_RANGE);
+        assert!((odds.probability(&FeatResult::PartialSuccess) - 0.12).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_fumble_ignores_modifier() {
+        let odds = feat_odds(100, DEFAULT_EXPLOSION_DEPTH, DEFAULT_FUMBLE_RANGE);
+        assert!((odds.probability(&FeatResult::CriticalFailure) - 0.01).abs() < EPSILON);
+        assert!((odds.probability(&FeatResult::CriticalSuccess) - 0.99).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_at_least_sl() {
+        let odds = feat_odds(12, DEFAULT_EXPLOSION_DEPTH, DEFAULT_FUMBLE_RANGE);
+        assert!(odds.at_least_sl(1) >= odds.at_least_sl(3));
+        assert!((odds.at_least_sl(5) - odds.probability(&FeatResult::CriticalSuccess)).abs() < EPSILON);
+    }
+}
@@ -0,0 +1,158 @@
+//! Parrying for Fantasy Express
+//!
+//! Parrying shifts points from Attack Bonus to Defensive Modifier for the
+//! round. Shifting all of your AB still leaves an attack at +0.
+//!
+//! | Situation                    | AB : DM |
+//! |------------------------------|---------|
+//! | Single foe                   | 1:1     |
+//! | Multiple foes                | 2:1     |
+//! | With a shield, multiple foes | 1:1     |
+//! | Improvised weapon            | 2:1 (single) / 3:1 (multiple) |
+//! | Stunned                      | 3:1     |
+
+/// Who you're parrying against
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ParryFoes {
+    #[default]
+    Single,
+    Multiple,
+}
+
+impl ParryFoes {
+    /// Both options, single first
+    pub const ALL: [ParryFoes; 2] = [ParryFoes::Single, ParryFoes::Multiple];
+
+    /// Parse a slash command choice value
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|f| f.choice() == value)
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            ParryFoes::Single => "single",
+            ParryFoes::Multiple => "multiple",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            ParryFoes::Single => "Single foe",
+            ParryFoes::Multiple => "Multiple foes",
+        }
+    }
+}
+
+/// Everything that changes the Parrying ratio
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParryOptions {
+    pub foes: ParryFoes,
+    /// Parrying with an improvised weapon
+    pub improvised: bool,
+    /// Carrying a shield, which parries multiple foes at 1:1
+    pub shield: bool,
+    /// Stunned combatants parry at 3:1
+    pub stunned: bool,
+}
+
+impl ParryOptions {
+    /// AB points spent per point of DM gained
+    pub fn ratio(&self) -> i32 {
+        // A shield lets you parry several foes as if facing one
+        let multiple = self.foes == ParryFoes::Multiple && !self.shield;
+        let ratio = match (self.improvised, multiple) {
+            (false, false) => 1,
+            (false, true) => 2,
+            (true, false) => 2,
+            (true, true) => 3,
+        };
+        if self.stunned {
+            ratio.max(3)
+        } else {
+            ratio
+        }
+    }
+
+    /// Why the ratio is what it is, e.g. "2:1, Multiple foes"
+    pub fn describe(&self) -> String {
+        let mut reasons = vec![self.foes.name()];
+        if self.shield && self.foes == ParryFoes::Multiple {
+            reasons.push("shield");
+        }
+        if self.improvised {
+            reasons.push("improvised weapon");
+        }
+        if self.stunned {
+            reasons.push("Stunned");
+        }
+        format!("{}:1, {}", self.ratio(), reasons.join(", "))
+    }
+}
+
+/// AB shifted to DM for the round
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parry {
+    /// AB points given up
+    pub ab_spent: i32,
+    /// DM gained for the round
+    pub dm_gained: i32,
+    /// Attack Bonus left for the attack (never below +0)
+    pub attack_bonus: i32,
+}
+
+/// Shift `ab_spent` points of `attack_bonus` to DM
+///
+/// You can't shift more than your Attack Bonus; points that don't make up a
+/// whole point of DM at the ratio are still spent.
+pub fn allocate_parry(attack_bonus: i32, ab_spent: i32, options: &ParryOptions) -> Result<Parry, String> {
+    if ab_spent > attack_bonus.max(0) {
+        return Err(format!(
+            "⚠️ Can't Parry with {} AB: you only have {:+} to shift",
+            ab_spent, attack_bonus
+        ));
+    }
+    Ok(Parry {
+        ab_spent,
+        dm_gained: ab_spent / options.ratio(),
+        attack_bonus: (attack_bonus - ab_spent).max(0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ratios() {
+        let single = ParryOptions::default();
+        let multiple = ParryOptions {
+            foes: ParryFoes::Multiple,
+            ..single
+        };
+        assert_eq!(single.ratio(), 1);
+        assert_eq!(multiple.ratio(), 2);
+        assert_eq!(ParryOptions { shield: true, ..multiple }.ratio(), 1);
+        assert_eq!(ParryOptions { improvised: true, ..single }.ratio(), 2);
+        assert_eq!(ParryOptions { improvised: true, ..multiple }.ratio(), 3);
+        assert_eq!(ParryOptions { stunned: true, ..single }.ratio(), 3);
+    }
+
+    #[test]
+    fn test_allocation() {
+        let multiple = ParryOptions {
+            foes: ParryFoes::Multiple,
+            ..Default::default()
+        };
+        let parry = allocate_parry(12, 7, &multiple).unwrap();
+        assert_eq!((parry.dm_gained, parry.attack_bonus), (3, 5));
+
+        // Shifting everything still leaves a +0 attack
+        let all_in = allocate_parry(12, 12, &ParryOptions::default()).unwrap();
+        assert_eq!((all_in.dm_gained, all_in.attack_bonus), (12, 0));
+
+        assert!(allocate_parry(12, 13, &multiple).is_err());
+        assert!(allocate_parry(-2, 1, &multiple).is_err());
+    }
+}
@@ -0,0 +1,204 @@
+//! Ranged attack modifiers for Fantasy Express
+//!
+//! The weapon's Range sets the bands; attacks beyond 4x Range are impossible.
+//!
+//! | Band        | Distance         | Modifier |
+//! |-------------|------------------|----------|
+//! | Point Blank | up to 0.5x Range | +4       |
+//! | Short       | 0.5x-1x Range    | +0       |
+//! | Medium      | 1x-2x Range      | -4       |
+//! | Long        | 2x-3x Range      | -10      |
+//! | Extreme     | 3x-4x Range      | -16      |
+//!
+//! Aiming the round before the attack adds +1 (Free Action), +2 (Half
+//! Action) or +4 (Full Action).
+
+/// Range band of a ranged attack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RangeBand {
+    PointBlank,
+    Short,
+    Medium,
+    Long,
+    Extreme,
+}
+
+impl RangeBand {
+    /// Band for an attack at `distance` with a weapon of `weapon_range`
+    ///
+    /// Returns None beyond 4x Range, where the attack is impossible.
+    pub fn from_distance(weapon_range: u32, distance: u32) -> Option<Self> {
+        if distance * 2 <= weapon_range {
+            Some(RangeBand::PointBlank)
+        } else if distance <= weapon_range {
+            Some(RangeBand::Short)
+        } else if distance <= weapon_range * 2 {
+            Some(RangeBand::Medium)
+        } else if distance <= weapon_range * 3 {
+            Some(RangeBand::Long)
+        } else if distance <= weapon_range * 4 {
+            Some(RangeBand::Extreme)
+        } else {
+            None
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            RangeBand::PointBlank => "Point Blank",
+            RangeBand::Short => "Short",
+            RangeBand::Medium => "Medium",
+            RangeBand::Long => "Long",
+            RangeBand::Extreme => "Extreme",
+        }
+    }
+
+    /// Attack modifier
+    pub fn modifier(&self) -> i32 {
+        match self {
+            RangeBand::PointBlank => 4,
+            RangeBand::Short => 0,
+            RangeBand::Medium => -4,
+            RangeBand::Long => -10,
+            RangeBand::Extreme => -16,
+        }
+    }
+}
+
+/// Aiming taken the round before the attack
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Aim {
+    #[default]
+    None,
+    /// Free Action (+1)
+    Free,
+    /// Half Action (+2)
+    Half,
+    /// Full Action (+4)
+    Full,
+}
+
+impl Aim {
+    /// All aim options, least first
+    pub const ALL: [Aim; 4] = [Aim::None, Aim::Free, Aim::Half, Aim::Full];
+
+    /// Parse a slash command choice value
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|a| a.choice() == value)
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            Aim::None => "none",
+            Aim::Free => "free",
+            Aim::Half => "half",
+            Aim::Full => "full",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Aim::None => "No aiming",
+            Aim::Free => "Aimed (Free Action)",
+            Aim::Half => "Aimed (Half Action)",
+            Aim::Full => "Aimed (Full Action)",
+        }
+    }
+
+    /// Attack modifier
+    pub fn modifier(&self) -> i32 {
+        match self {
+            Aim::None => 0,
+            Aim::Free => 1,
+            Aim::Half => 2,
+            Aim::Full => 4,
+        }
+    }
+}
+
+/// Attack modifiers for a ranged attack
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RangedModifiers {
+    pub weapon_range: u32,
+    pub distance: u32,
+    pub band: RangeBand,
+    pub aim: Aim,
+}
+
+impl RangedModifiers {
+    /// Work out the band, or explain why the shot is impossible
+    pub fn new(weapon_range: u32, distance: u32, aim: Aim) -> Result<Self, String> {
+        if weapon_range == 0 {
+            return Err("⚠️ The weapon's Range must be at least 1'".to_string());
+        }
+        let band = RangeBand::from_distance(weapon_range, distance).ok_or_else(|| {
+            format!(
+                "⚠️ {}' is beyond 4x the weapon's Range ({}'): the attack is impossible",
+                distance,
+                weapon_range * 4
+            )
+        })?;
+        Ok(RangedModifiers {
+            weapon_range,
+            distance,
+            band,
+            aim,
+        })
+    }
+
+    /// Each modifier that applies, with its value
+    pub fn itemize(&self) -> Vec<(String, i32)> {
+        let mut items = vec![(
+            format!("{} range ({}' of {}')", self.band.name(), self.distance, self.weapon_range),
+            self.band.modifier(),
+        )];
+        if self.aim != Aim::None {
+            items.push((self.aim.name().to_string(), self.aim.modifier()));
+        }
+        items
+    }
+
+    /// Total attack modifier
+    pub fn total(&self) -> i32 {
+        self.itemize().iter().map(|(_, modifier)| modifier).sum()
+    }
+
+    /// Format the modifiers for Discord display
+    pub fn breakdown(&self) -> String {
+        self.itemize()
+            .iter()
+            .map(|(name, modifier)| format!("{} {:+}", name, modifier))
+            .collect::<Vec<_>>()
+            .join(" | ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_band_edges() {
+        let band = |distance| RangeBand::from_distance(30, distance);
+        assert_eq!(band(0), Some(RangeBand::PointBlank));
+        assert_eq!(band(15), Some(RangeBand::PointBlank));
+        assert_eq!(band(16), Some(RangeBand::Short));
+        assert_eq!(band(30), Some(RangeBand::Short));
+        assert_eq!(band(60), Some(RangeBand::Medium));
+        assert_eq!(band(90), Some(RangeBand::Long));
+        assert_eq!(band(120), Some(RangeBand::Extreme));
+        assert_eq!(band(121), None);
+    }
+
+    #[test]
+    fn test_ranged_modifiers() {
+        let shot = RangedModifiers::new(30, 75, Aim::Full).unwrap();
+        assert_eq!(shot.band, RangeBand::Long);
+        assert_eq!(shot.total(), -10 + 4);
+        assert!(RangedModifiers::new(30, 200, Aim::None).is_err());
+        assert!(RangedModifiers::new(0, 10, Aim::None).is_err());
+    }
+}
@@ -0,0 +1,309 @@
+//! Magical Resonance for Fantasy Express spell casting
+//!
+//! Rolling doubles on a Casting Roll where the doubled number is ≤ the spell
+//! Tier draws the Darkmaster's attention. The Resonance roll is 2d10
+//! open-ended plus the modifiers below.
+
+use super::roll::DiceResult;
+
+/// Where the spell is cast
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CastingLocation {
+    Normal,
+    /// -4
+    SafeHaven,
+    /// +4
+    Blighted,
+}
+
+impl CastingLocation {
+    /// All locations, in table order
+    pub const ALL: [CastingLocation; 3] = [
+        CastingLocation::Normal,
+        CastingLocation::SafeHaven,
+        CastingLocation::Blighted,
+    ];
+
+    /// Parse a slash command choice value
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|l| l.choice() == value)
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            CastingLocation::Normal => "normal",
+            CastingLocation::SafeHaven => "safe_haven",
+            CastingLocation::Blighted => "blighted",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            CastingLocation::Normal => "Normal",
+            CastingLocation::SafeHaven => "Safe Haven",
+            CastingLocation::Blighted => "Blighted/Darkland",
+        }
+    }
+
+    /// Resonance roll modifier
+    pub fn modifier(&self) -> i32 {
+        match self {
+            CastingLocation::Normal => 0,
+            CastingLocation::SafeHaven => -4,
+            CastingLocation::Blighted => 4,
+        }
+    }
+}
+
+/// Kind of spell being cast
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpellNature {
+    Neutral,
+    /// -4
+    Healing,
+    /// -2
+    NatureIllusion,
+    /// +4
+    Attack,
+    /// +6
+    DarkInfernal,
+}
+
+impl SpellNature {
+    /// All spell natures, in table order
+    pub const ALL: [SpellNature; 5] = [
+        SpellNature::Neutral,
+        SpellNature::Healing,
+        SpellNature::NatureIllusion,
+        SpellNature::Attack,
+        SpellNature::DarkInfernal,
+    ];
+
+    /// Parse a slash command choice value
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|n| n.choice() == value)
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            SpellNature::Neutral => "neutral",
+            SpellNature::Healing => "healing",
+            SpellNature::NatureIllusion => "nature",
+            SpellNature::Attack => "attack",
+            SpellNature::DarkInfernal => "dark",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            SpellNature::Neutral => "Other",
+            SpellNature::Healing => "Healing/Beneficial",
+            SpellNature::NatureIllusion => "Nature/Illusionary",
+            SpellNature::Attack => "Attack/Harmful",
+            SpellNature::DarkInfernal => "Dark/Infernal",
+        }
+    }
+
+    /// Resonance roll modifier
+    pub fn modifier(&self) -> i32 {
+        match self {
+            SpellNature::Neutral => 0,
+            SpellNature::Healing => -4,
+            SpellNature::NatureIllusion => -2,
+            SpellNature::Attack => 4,
+            SpellNature::DarkInfernal => 6,
+        }
+    }
+}
+
+/// Everything that modifies a Resonance roll
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ResonanceModifiers {
+    /// Spell Tier (+Tier)
+    pub tier: i32,
+    /// Abnormal Spell Failure modifiers (+ASF)
+    pub asf: i32,
+    pub location: CastingLocation,
+    pub nature: SpellNature,
+}
+
+impl ResonanceModifiers {
+    /// Total modifier to the Resonance roll
+    pub fn total(&self) -> i32 {
+        self.tier + self.asf + self.location.modifier() + self.nature.modifier()
+    }
+
+    /// Format the modifiers that apply, e.g. "Tier +3 | Blighted/Darkland +4"
+    pub fn breakdown(&self) -> String {
+        let mut parts = vec![format!("Tier {:+}", self.tier)];
+        if self.asf != 0 {
+            parts.push(format!("ASF {:+}", self.asf));
+        }
+        if self.location.modifier() != 0 {
+            parts.push(format!("{} {:+}", self.location.name(), self.location.modifier()));
+        }
+        if self.nature.modifier() != 0 {
+            parts.push(format!("{} {:+}", self.nature.name(), self.nature.modifier()));
+        }
+        parts.join(" | ")
+    }
+}
+
+/// Result on the Magical Resonance table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResonanceResult {
+    /// ≤14
+    Nothing,
+    /// 15-18
+    Shiver,
+    /// 19-22
+    Awareness,
+    /// 23-26
+    Attention,
+    /// 27-30
+    Pursuit,
+    /// 31-34
+    Assault,
+    /// 35+
+    Lieutenant,
+}
+
+impl ResonanceResult {
+    /// Get emoji representation
+    pub fn emoji(&self) -> &'static str {
+        match self {
+            ResonanceResult::Nothing => "🌫️",
+            ResonanceResult::Shiver => "🥶",
+            ResonanceResult::Awareness => "👁️",
+            ResonanceResult::Attention => "⚠️",
+            ResonanceResult::Pursuit => "🏃",
+            ResonanceResult::Assault => "⚔️",
+            ResonanceResult::Lieutenant => "💀",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            ResonanceResult::Nothing => "NOTHING",
+            ResonanceResult::Shiver => "SHIVER",
+            ResonanceResult::Awareness => "AWARENESS",
+            ResonanceResult::Attention => "ATTENTION",
+            ResonanceResult::Pursuit => "PURSUIT",
+            ResonanceResult::Assault => "ASSAULT",
+            ResonanceResult::Lieutenant => "LIEUTENANT",
+        }
+    }
+
+    /// Get description of the result
+    pub fn description(&self) -> &'static str {
+        match self {
+            ResonanceResult::Nothing => "Escaped notice.",
+            ResonanceResult::Shiver => "Brief attention, then it passed.",
+            ResonanceResult::Awareness => {
+                "The Darkmaster notices. The next spell automatically triggers a Resonance roll."
+            }
+            ResonanceResult::Attention => {
+                "The Darkmaster sends agents OR the caster is Stunned 1-4 rounds."
+            }
+            ResonanceResult::Pursuit => {
+                "A capture team is sent OR the caster has visions, unable to act until an Assessment Roll."
+            }
+            ResonanceResult::Assault => {
+                "A destruction team is sent OR the caster is incapacitated 1d10 rounds, loses all Mana OR answers one question."
+            }
+            ResonanceResult::Lieutenant => "An overwhelming servant of the Darkmaster arrives!",
+        }
+    }
+}
+
+/// Check whether a Casting Roll triggers Magical Resonance
+///
+/// Resonance happens on doubles where the doubled number is ≤ the spell Tier.
+pub fn triggers_resonance(result: &DiceResult, tier: i32) -> bool {
+    let (d1, d2) = result.base_rolls;
+    d1 == d2 && (d1 as i32) <= tier
+}
+
+/// Resolve a Resonance roll total on the Resonance table
+pub fn resolve_resonance(total: i32) -> ResonanceResult {
+    match total {
+        t if t <= 14 => ResonanceResult::Nothing,
+        15..=18 => ResonanceResult::Shiver,
+        19..=22 => ResonanceResult::Awareness,
+        23..=26 => ResonanceResult::Attention,
+        27..=30 => ResonanceResult::Pursuit,
+        31..=34 => ResonanceResult::Assault,
+        _ => ResonanceResult::Lieutenant, // 35+
+    }
+}
+
+/// Format a Resonance result for Discord display
+pub fn format_resonance_result(result: ResonanceResult, total: i32) -> String {
+    format!(
+        "{} **{}** (Total: {})\n{}",
+        result.emoji(),
+        result.name(),
+        total,
+        result.description()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dice(d1: u8, d2: u8) -> DiceResult {
+        DiceResult {
+            base_rolls: (d1, d2),
+            explosions: vec![],
+            raw_total: (d1 + d2) as i32,
+            is_fumble: false,
+            exploded: false,
+        }
+    }
+
+    #[test]
+    fn test_trigger_needs_doubles_within_tier() {
+        assert!(triggers_resonance(&dice(3, 3), 3));
+        assert!(triggers_resonance(&dice(1, 1), 5));
+        assert!(!triggers_resonance(&dice(4, 4), 3));
+        assert!(!triggers_resonance(&dice(2, 3), 5));
+    }
+
+    #[test]
+    fn test_modifier_total() {
+        let modifiers = ResonanceModifiers {
+            tier: 3,
+            asf: 2,
+            location: CastingLocation::Blighted,
+            nature: SpellNature::DarkInfernal,
+        };
+        assert_eq!(modifiers.total(), 3 + 2 + 4 + 6);
+
+        let modifiers = ResonanceModifiers {
+            tier: 1,
+            asf: 0,
+            location: CastingLocation::SafeHaven,
+            nature: SpellNature::Healing,
+        };
+        assert_eq!(modifiers.total(), -7);
+        assert_eq!(modifiers.breakdown(), "Tier +1 | Safe Haven -4 | Healing/Beneficial -4");
+    }
+
+    #[test]
+    fn test_resonance_table() {
+        assert_eq!(resolve_resonance(-3), ResonanceResult::Nothing);
+        assert_eq!(resolve_resonance(14), ResonanceResult::Nothing);
+        assert_eq!(resolve_resonance(15), ResonanceResult::Shiver);
+        assert_eq!(resolve_resonance(22), ResonanceResult::Awareness);
+        assert_eq!(resolve_resonance(23), ResonanceResult::Attention);
+        assert_eq!(resolve_resonance(30), ResonanceResult::Pursuit);
+        assert_eq!(resolve_resonance(34), ResonanceResult::Assault);
+        assert_eq!(resolve_resonance(35), ResonanceResult::Lieutenant);
+    }
+}
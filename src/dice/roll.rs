@@ -2,9 +2,12 @@
 //!
 //! Implements 2d10 open-ended dice with:
 //! - Explosion on 19-20 (roll again, add to total)
-//! - Critical Failure (Fumble) on unmodified 2
+//! - Critical Failure (Fumble) on unmodified 2, or within a wider fumble range
 
-use rand::Rng;
+use super::source::DiceSource;
+
+/// Default critical failure range: only an unmodified 2 fumbles
+pub const DEFAULT_FUMBLE_RANGE: u8 = 2;
 
 /// Result of a 2d10 open-ended roll
 #[derive(Debug, Clone)]
@@ -15,7 +18,7 @@ pub struct DiceResult {
     pub explosions: Vec<(u8, u8)>,
     /// Raw total before modifiers
     pub raw_total: i32,
-    /// Whether this is a fumble (unmodified 2)
+    /// Whether this is a fumble (unmodified roll within the fumble range)
     pub is_fumble: bool,
     /// Whether an explosion occurred
     #[allow(dead_code)]
@@ -29,23 +32,25 @@ impl DiceResult {
     }
 }
 
-/// Roll a single d10 (1-10)
-fn roll_d10() -> u8 {
-    rand::thread_rng().gen_range(1..=10)
-}
-
 /// Roll 2d10 with open-ended explosions
 ///
 /// # Rules
 /// - Roll 2d10 and sum
 /// - If unmodified total is 19 or 20, roll again and add (chain explosions)
 /// - If unmodified total is 2 (both dice show 1), it's a fumble
-pub fn roll_2d10_open() -> DiceResult {
-    let d1 = roll_d10();
-    let d2 = roll_d10();
+pub fn roll_2d10_open(dice: &mut dyn DiceSource) -> DiceResult {
+    roll_2d10_open_with_fumble_range(dice, DEFAULT_FUMBLE_RANGE)
+}
+
+/// Roll 2d10 open-ended where any unmodified total from 2 to `fumble_range` fumbles
+///
+/// Abnormal Spell Failure widens the range to 2 + ASF; some weapons fumble on 3-4+.
+pub fn roll_2d10_open_with_fumble_range(dice: &mut dyn DiceSource, fumble_range: u8) -> DiceResult {
+    let d1 = dice.roll_d10();
+    let d2 = dice.roll_d10();
     let base_sum = d1 + d2;
 
-    let is_fumble = d1 == 1 && d2 == 1;
+    let is_fumble = base_sum <= fumble_range.max(DEFAULT_FUMBLE_RANGE);
     let mut explosions = Vec::new();
     let mut total = base_sum as i32;
 
@@ -55,8 +60,8 @@ pub fn roll_2d10_open() -> DiceResult {
         let mut last_roll = base_sum;
         let mut explosion_count = 0;
         while last_roll >= 19 && explosion_count < 100 {
-            let exp_d1 = roll_d10();
-            let exp_d2 = roll_d10();
+            let exp_d1 = dice.roll_d10();
+            let exp_d2 = dice.roll_d10();
             let explosion_sum = exp_d1 + exp_d2;
             explosions.push((exp_d1, exp_d2));
             total += explosion_sum as i32;
@@ -77,8 +82,14 @@ pub fn roll_2d10_open() -> DiceResult {
 }
 
 /// Roll 2d10 open-ended with a modifier applied
-pub fn roll_with_modifier(modifier: i32) -> (DiceResult, i32) {
-    let result = roll_2d10_open();
+///
+/// Use `DEFAULT_FUMBLE_RANGE` unless something widens the critical failure range.
+pub fn roll_with_modifier(
+    dice: &mut dyn DiceSource,
+    modifier: i32,
+    fumble_range: u8,
+) -> (DiceResult, i32) {
+    let result = roll_2d10_open_with_fumble_range(dice, fumble_range);
     let final_total = result.raw_total + modifier;
     (result, final_total)
 }
@@ -87,9 +98,9 @@ pub fn roll_with_modifier(modifier: i32) -> (DiceResult, i32) {
 ///
 /// Unlike combat and skill rolls, initiative uses standard 2d10
 /// without the explosion mechanic on 19-20.
-pub fn roll_2d10_closed() -> (u8, u8, i32) {
-    let d1 = roll_d10();
-    let d2 = roll_d10();
+pub fn roll_2d10_closed(dice: &mut dyn DiceSource) -> (u8, u8, i32) {
+    let d1 = dice.roll_d10();
+    let d2 = dice.roll_d10();
     (d1, d2, (d1 + d2) as i32)
 }
 
@@ -131,13 +142,52 @@ pub fn format_roll(result: &DiceResult, modifier: Option<i32>) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::dice::source::ScriptedSource;
 
     #[test]
-    fn test_d10_range() {
-        for _ in 0..100 {
-            let roll = roll_d10();
-            assert!(roll >= 1 && roll <= 10, "d10 out of range: {}", roll);
-        }
+    fn test_open_roll_no_explosion() {
+        let mut dice = ScriptedSource::new(&[6, 8]);
+        let result = roll_2d10_open(&mut dice);
+        assert_eq!(result.base_rolls, (6, 8));
+        assert_eq!(result.raw_total, 14);
+        assert!(result.explosions.is_empty());
+        assert!(!result.is_fumble);
+    }
+
+    #[test]
+    fn test_open_roll_chained_explosion() {
+        // 10+9 explodes, 10+10 explodes again, 4+3 stops the chain
+        let mut dice = ScriptedSource::new(&[10, 9, 10, 10, 4, 3]);
+        let result = roll_2d10_open(&mut dice);
+        assert_eq!(result.explosions, vec![(10, 10), (4, 3)]);
+        assert_eq!(result.raw_total, 19 + 20 + 7);
+        assert!(result.exploded);
+        assert_eq!(dice.remaining(), 0);
+    }
+
+    #[test]
+    fn test_open_roll_fumble() {
+        let mut dice = ScriptedSource::new(&[1, 1]);
+        let (result, total) = roll_with_modifier(&mut dice, 30, DEFAULT_FUMBLE_RANGE);
+        assert!(result.is_fumble);
+        assert_eq!(total, 32);
+    }
+
+    #[test]
+    fn test_wider_fumble_range() {
+        let mut dice = ScriptedSource::new(&[2, 2, 2, 3]);
+        assert!(roll_2d10_open_with_fumble_range(&mut dice, 4).is_fumble);
+        assert!(!roll_2d10_open_with_fumble_range(&mut dice, 4).is_fumble);
+
+        let mut dice = ScriptedSource::new(&[1, 2]);
+        assert!(!roll_2d10_open(&mut dice).is_fumble);
+    }
+
+    #[test]
+    fn test_closed_roll_does_not_explode() {
+        let mut dice = ScriptedSource::new(&[10, 10]);
+        assert_eq!(roll_2d10_closed(&mut dice), (10, 10, 20));
+        assert_eq!(dice.remaining(), 0);
     }
 
     #[test]
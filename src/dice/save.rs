@@ -0,0 +1,229 @@
+//! Save Rolls for Fantasy Express
+//!
+//! Save Roll bonus = 7 + Stat + Kin Modifier + Character Level
+//! Save Roll total = 2d10 (open-ended) + Save Roll bonus - Attack Level/Tier
+//!
+//! A total of 20+ resists the effect. Otherwise the margin, 20 - total, is
+//! the Points of Failure many effects scale by.
+
+use super::feat::{resolve_feat, FeatResult, RollKind};
+use super::roll::{roll_with_modifier, DiceResult, DEFAULT_FUMBLE_RANGE};
+use super::source::DiceSource;
+
+/// Base value every Save Roll bonus starts from
+pub const SAVE_BASE: i32 = 7;
+
+/// The three Save Roll types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveType {
+    /// TSR: physical threats, poisons, diseases, fatigue
+    Toughness,
+    /// RSR: sudden threats, traps
+    Reflex,
+    /// WSR: mental and spiritual effects
+    Will,
+}
+
+impl SaveType {
+    /// All save types, in table order
+    pub const ALL: [SaveType; 3] = [SaveType::Toughness, SaveType::Reflex, SaveType::Will];
+
+    /// Parse a slash command choice value (case-insensitive)
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|s| s.choice().eq_ignore_ascii_case(value))
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            SaveType::Toughness => "TSR",
+            SaveType::Reflex => "RSR",
+            SaveType::Will => "WSR",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            SaveType::Toughness => "Toughness (TSR)",
+            SaveType::Reflex => "Reflex (RSR)",
+            SaveType::Will => "Will (WSR)",
+        }
+    }
+
+    /// Stat the save is based on
+    pub fn stat(&self) -> &'static str {
+        match self {
+            SaveType::Toughness => "Stamina",
+            SaveType::Reflex => "Dexterity",
+            SaveType::Will => "Resolve",
+        }
+    }
+}
+
+/// Save Roll bonus: 7 + Stat + Kin Modifier + Character Level
+pub fn save_bonus(stat: i32, kin: i32, level: i32) -> i32 {
+    SAVE_BASE + stat + kin + level
+}
+
+/// Target a Save total must reach to resist
+pub const SAVE_TARGET: i32 = 20;
+
+/// Whether a Save resisted the effect, and by how much it failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveOutcome {
+    Resisted,
+    Failed {
+        /// 20 - total, at least 1
+        points_of_failure: i32,
+        /// Failed on a Critical Failure, whatever the total
+        fumbled: bool,
+    },
+}
+
+impl SaveOutcome {
+    /// Points of Failure (0 when resisted)
+    pub fn points_of_failure(&self) -> i32 {
+        match self {
+            SaveOutcome::Resisted => 0,
+            SaveOutcome::Failed { points_of_failure, .. } => *points_of_failure,
+        }
+    }
+
+    /// Short result, e.g. "✅ Resisted" or "❌ Failed by 4"
+    pub fn label(&self) -> String {
+        match self {
+            SaveOutcome::Resisted => "✅ Resisted".to_string(),
+            SaveOutcome::Failed {
+                points_of_failure,
+                fumbled: false,
+            } => format!("❌ Failed by {}", points_of_failure),
+            SaveOutcome::Failed {
+                points_of_failure,
+                fumbled: true,
+            } => format!("💀 Fumbled, failed by {}", points_of_failure),
+        }
+    }
+}
+
+/// Resolve a Save total through the FEAT table
+///
+/// Only a Success resists: a Partial Success still fails by 1-4. A Critical
+/// Failure fails by at least 1 however high the total.
+pub fn resolve_save(total: i32, is_fumble: bool) -> SaveOutcome {
+    let feat_result = resolve_feat(total, is_fumble);
+    if feat_result.is_success() {
+        SaveOutcome::Resisted
+    } else {
+        SaveOutcome::Failed {
+            points_of_failure: (SAVE_TARGET - total).max(1),
+            fumbled: feat_result == FeatResult::CriticalFailure,
+        }
+    }
+}
+
+/// Format a Save's outcome for Discord display: the FEAT Save description,
+/// plus the Points of Failure when it fails
+pub fn format_save_outcome(outcome: &SaveOutcome, total: i32) -> String {
+    let fumbled = matches!(outcome, SaveOutcome::Failed { fumbled: true, .. });
+    let description = resolve_feat(total, fumbled).description(RollKind::Save);
+    if *outcome == SaveOutcome::Resisted {
+        return format!("✅ **RESISTED** (Total: {})\n{}", total, description);
+    }
+
+    let points = outcome.points_of_failure();
+    format!(
+        "❌ **FAILED by {}** (Total: {})\n{}\n{} Point{} of Failure: effects that scale with the margin use {}.",
+        points,
+        total,
+        description,
+        points,
+        if points == 1 { "" } else { "s" },
+        points
+    )
+}
+
+/// A rolled Save: 2d10 (open-ended) + bonus - Attack Level/Tier
+#[derive(Debug, Clone)]
+pub struct SaveRoll {
+    pub dice: DiceResult,
+    /// Bonus minus Attack Level
+    pub modifier: i32,
+    pub total: i32,
+    pub outcome: SaveOutcome,
+}
+
+impl SaveRoll {
+    /// Whether the effect is resisted (a Success, 20+)
+    pub fn resisted(&self) -> bool {
+        self.outcome == SaveOutcome::Resisted
+    }
+}
+
+/// Roll a Save with `bonus` against an Attack Level or spell Tier
+pub fn roll_save(dice: &mut dyn DiceSource, bonus: i32, attack_level: i32) -> SaveRoll {
+    let modifier = bonus - attack_level;
+    let (result, total) = roll_with_modifier(dice, modifier, DEFAULT_FUMBLE_RANGE);
+    SaveRoll {
+        outcome: resolve_save(total, result.is_fumble),
+        dice: result,
+        modifier,
+        total,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dice::source::ScriptedSource;
+
+    #[test]
+    fn test_choice_round_trip() {
+        for save in SaveType::ALL {
+            assert_eq!(SaveType::from_choice(save.choice()), Some(save));
+        }
+        assert_eq!(SaveType::from_choice("rsr"), Some(SaveType::Reflex));
+        assert_eq!(SaveType::from_choice("XSR"), None);
+    }
+
+    #[test]
+    fn test_save_bonus() {
+        assert_eq!(save_bonus(0, 0, 0), 7);
+        assert_eq!(save_bonus(3, 2, 4), 16);
+        assert_eq!(save_bonus(-2, 0, 1), 6);
+    }
+
+    #[test]
+    fn test_roll_save_against_attack_level() {
+        let mut dice = ScriptedSource::new(&[6, 7]);
+        let save = roll_save(&mut dice, 12, 3);
+        assert_eq!(save.total, 13 + 12 - 3);
+        assert!(save.resisted());
+
+        let mut dice = ScriptedSource::new(&[6, 7]);
+        let failed = roll_save(&mut dice, 9, 3);
+        assert!(!failed.resisted());
+        assert_eq!(failed.outcome.points_of_failure(), 20 - 19);
+    }
+
+    #[test]
+    fn test_points_of_failure() {
+        assert_eq!(resolve_save(20, false), SaveOutcome::Resisted);
+        // A Partial Success still fails the save
+        assert_eq!(resolve_save(17, false).points_of_failure(), 3);
+        assert_eq!(resolve_save(5, false).points_of_failure(), 15);
+        let fumble = resolve_save(24, true);
+        assert_eq!(
+            fumble,
+            SaveOutcome::Failed {
+                points_of_failure: 1,
+                fumbled: true
+            }
+        );
+        assert!(format_save_outcome(&fumble, 24).contains("FAILED by 1"));
+        assert!(format_save_outcome(&fumble, 24).contains("fails whatever the total"));
+        assert!(format_save_outcome(&resolve_save(17, false), 17).contains("still fails"));
+    }
+}
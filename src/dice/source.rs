@@ -0,0 +1,126 @@
+//! Dice sources for Fantasy Express rolls
+//!
+//! Every roll in the bot draws its d10s from a `DiceSource`, so a roll can be:
+//! - Seeded and replayed later (ChaCha seeded from a `u64`)
+//! - Scripted with exact dice values (for tests)
+
+#[cfg(test)]
+use std::collections::VecDeque;
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// Something that can roll a d10
+pub trait DiceSource {
+    /// Roll a single d10 (1-10)
+    fn roll_d10(&mut self) -> u8;
+}
+
+/// Dice source seeded from a number, so the same seed always replays the same dice
+#[derive(Debug, Clone)]
+pub struct SeededSource {
+    seed: u64,
+    rng: ChaCha8Rng,
+}
+
+impl SeededSource {
+    /// Create a source that replays the dice for `seed`
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: ChaCha8Rng::seed_from_u64(seed),
+        }
+    }
+
+    /// Create a source with a fresh random seed
+    ///
+    /// Seeds are kept within `u32` range so they fit in a Discord integer option.
+    pub fn from_entropy() -> Self {
+        Self::new(rand::thread_rng().gen::<u32>() as u64)
+    }
+
+    /// The seed this source was created with
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl DiceSource for SeededSource {
+    fn roll_d10(&mut self) -> u8 {
+        self.rng.gen_range(1..=10)
+    }
+}
+
+/// Dice source that returns a fixed sequence of d10 values
+///
+/// Panics if more dice are rolled than were scripted.
+#[cfg(test)]
+#[derive(Debug, Clone)]
+pub struct ScriptedSource {
+    rolls: VecDeque<u8>,
+}
+
+#[cfg(test)]
+impl ScriptedSource {
+    /// Create a source that yields `rolls` in order
+    pub fn new(rolls: &[u8]) -> Self {
+        Self {
+            rolls: rolls.iter().copied().collect(),
+        }
+    }
+
+    /// Number of scripted dice not yet rolled
+    pub fn remaining(&self) -> usize {
+        self.rolls.len()
+    }
+}
+
+#[cfg(test)]
+impl DiceSource for ScriptedSource {
+    fn roll_d10(&mut self) -> u8 {
+        self.rolls
+            .pop_front()
+            .expect("ScriptedSource ran out of dice")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded_range() {
+        let mut dice = SeededSource::new(42);
+        for _ in 0..100 {
+            let roll = dice.roll_d10();
+            assert!((1..=10).contains(&roll), "d10 out of range: {}", roll);
+        }
+    }
+
+    #[test]
+    fn test_same_seed_replays_same_dice() {
+        let mut first = SeededSource::new(1234);
+        let mut second = SeededSource::new(1234);
+        let a: Vec<u8> = (0..20).map(|_| first.roll_d10()).collect();
+        let b: Vec<u8> = (0..20).map(|_| second.roll_d10()).collect();
+        assert_eq!(a, b);
+        assert_eq!(first.seed(), 1234);
+    }
+
+    #[test]
+    fn test_scripted_sequence() {
+        let mut dice = ScriptedSource::new(&[3, 7, 10]);
+        assert_eq!(dice.roll_d10(), 3);
+        assert_eq!(dice.roll_d10(), 7);
+        assert_eq!(dice.remaining(), 1);
+        assert_eq!(dice.roll_d10(), 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "ran out of dice")]
+    fn test_scripted_exhausted() {
+        let mut dice = ScriptedSource::new(&[5]);
+        dice.roll_d10();
+        dice.roll_d10();
+    }
+}
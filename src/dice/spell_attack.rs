@@ -0,0 +1,260 @@
+//! Attack spells for Fantasy Express
+//!
+//! After a successful Casting Roll, the caster makes an Attack Roll (higher of
+//! Ranged or Arcana) and damage is resolved by the spell's shape:
+//!
+//! - **Bolt**: a single target, resolved as a ranged attack. There is no
+//!   Medium/Long/Extreme range: the bolt dissipates beyond the spell's range.
+//! - **Ball**: everyone in the radius takes the damage. The center target
+//!   makes an RSR to avoid +4 bonus damage; edge targets (within 3') make an
+//!   RSR for half damage.
+//! - **Cone**: as Ball, filling a cone 2.5' wide per 5' of length.
+//! - **Line**: 1' wide, hits everyone in its path; a successful RSR is a
+//!   complete dodge.
+
+use super::range::RangeBand;
+use super::save::{roll_save, SaveRoll};
+use super::source::DiceSource;
+
+/// Extra damage the center of a Ball or Cone takes on a failed RSR
+pub const CENTER_BONUS_HITS: i32 = 4;
+
+/// Shape of an attack spell
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpellShape {
+    Bolt,
+    Ball,
+    Cone,
+    Line,
+}
+
+impl SpellShape {
+    /// All shapes
+    pub const ALL: [SpellShape; 4] = [
+        SpellShape::Bolt,
+        SpellShape::Ball,
+        SpellShape::Cone,
+        SpellShape::Line,
+    ];
+
+    /// Parse a slash command choice value
+    pub fn from_choice(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.choice() == value)
+    }
+
+    /// Slash command choice value
+    pub fn choice(&self) -> &'static str {
+        match self {
+            SpellShape::Bolt => "bolt",
+            SpellShape::Ball => "ball",
+            SpellShape::Cone => "cone",
+            SpellShape::Line => "line",
+        }
+    }
+
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            SpellShape::Bolt => "Bolt",
+            SpellShape::Ball => "Ball",
+            SpellShape::Cone => "Cone",
+            SpellShape::Line => "Line",
+        }
+    }
+
+    /// Get description of how damage is resolved
+    pub fn description(&self) -> &'static str {
+        match self {
+            SpellShape::Bolt => "Single target; dissipates beyond the spell's range",
+            SpellShape::Ball => "Center: RSR to avoid +4 Hits; edge (within 3'): RSR for half",
+            SpellShape::Cone => "2.5' wide per 5' of length; center/edge as Ball",
+            SpellShape::Line => "1' wide, hits everyone in its path; RSR to dodge completely",
+        }
+    }
+}
+
+/// Where a target stands in a Ball or Cone
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AreaZone {
+    /// The targeted enemy at the center
+    Center,
+    /// Inside the area: full damage, no save
+    #[default]
+    Inside,
+    /// Within 3' of the edge
+    Edge,
+}
+
+impl AreaZone {
+    /// Get display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            AreaZone::Center => "center",
+            AreaZone::Inside => "inside",
+            AreaZone::Edge => "edge",
+        }
+    }
+}
+
+/// A target of an attack spell, with their RSR bonus
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpellTarget {
+    pub name: String,
+    pub save_bonus: i32,
+    pub zone: AreaZone,
+}
+
+/// Parse targets written as `Name:+bonus[:center|edge]`, separated by commas
+///
+/// e.g. "Goblin:+5:center, Orc:+3, Troll:+8:edge"
+pub fn parse_targets(input: &str) -> Result<Vec<SpellTarget>, String> {
+    let mut targets = Vec::new();
+    for entry in input.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let parts: Vec<&str> = entry.split(':').map(str::trim).collect();
+        let invalid = || format!("⚠️ Couldn't read target `{}`: use `Name:+bonus` or `Name:+bonus:edge`", entry);
+        let (name, bonus) = match parts.as_slice() {
+            [name, bonus, ..] if !name.is_empty() => (name, bonus),
+            _ => return Err(invalid()),
+        };
+        let save_bonus = bonus.trim_start_matches('+').parse::<i32>().map_err(|_| invalid())?;
+        let zone = match parts.get(2).map(|z| z.to_ascii_lowercase()).as_deref() {
+            None | Some("inside") => AreaZone::Inside,
+            Some("center") | Some("centre") => AreaZone::Center,
+            Some("edge") => AreaZone::Edge,
+            Some(_) => return Err(invalid()),
+        };
+        targets.push(SpellTarget {
+            name: name.to_string(),
+            save_bonus,
+            zone,
+        });
+    }
+    if targets.is_empty() {
+        return Err("⚠️ List at least one target, e.g. `Goblin:+5`".to_string());
+    }
+    Ok(targets)
+}
+
+/// Attack modifier for a Bolt at `distance` with a spell range of `spell_range`
+///
+/// Point Blank +4 or Short 0; returns None when the bolt dissipates.
+pub fn bolt_range_modifier(spell_range: u32, distance: u32) -> Option<i32> {
+    match RangeBand::from_distance(spell_range, distance)? {
+        band @ (RangeBand::PointBlank | RangeBand::Short) => Some(band.modifier()),
+        _ => None,
+    }
+}
+
+/// Width at the far end of a cone `length` feet long (2.5' per 5')
+pub fn cone_width(length: u32) -> f32 {
+    length as f32 / 2.0
+}
+
+/// How one target fared against an attack spell
+#[derive(Debug, Clone)]
+pub struct TargetOutcome {
+    pub target: SpellTarget,
+    /// RSR rolled, if the target got one
+    pub save: Option<SaveRoll>,
+    pub hits: i32,
+    /// Why the Hits differ from the base damage
+    pub note: &'static str,
+}
+
+/// Damage to one target from `hits` base damage, given how their RSR went
+pub fn area_damage(shape: SpellShape, zone: AreaZone, hits: i32, resisted: Option<bool>) -> (i32, &'static str) {
+    match (shape, zone, resisted) {
+        (SpellShape::Line, _, Some(true)) => (0, "dodged"),
+        (SpellShape::Ball | SpellShape::Cone, AreaZone::Center, Some(false)) => {
+            (hits + CENTER_BONUS_HITS, "+4 at the center")
+        }
+        (SpellShape::Ball | SpellShape::Cone, AreaZone::Center, Some(true)) => {
+            (hits, "avoided the +4")
+        }
+        (SpellShape::Ball | SpellShape::Cone, AreaZone::Edge, Some(true)) => (hits / 2, "half at the edge"),
+        _ => (hits, ""),
+    }
+}
+
+/// Whether a target in `zone` gets an RSR against `shape`
+pub fn gets_save(shape: SpellShape, zone: AreaZone) -> bool {
+    match shape {
+        SpellShape::Bolt => false,
+        SpellShape::Ball | SpellShape::Cone => zone != AreaZone::Inside,
+        SpellShape::Line => true,
+    }
+}
+
+/// Roll each target's RSR against the spell's `tier` and work out their damage
+pub fn resolve_targets(
+    dice: &mut dyn DiceSource,
+    shape: SpellShape,
+    targets: &[SpellTarget],
+    hits: i32,
+    tier: i32,
+) -> Vec<TargetOutcome> {
+    targets
+        .iter()
+        .map(|target| {
+            let save = gets_save(shape, target.zone).then(|| roll_save(dice, target.save_bonus, tier));
+            let (hits, note) = area_damage(shape, target.zone, hits, save.as_ref().map(|s| s.resisted()));
+            TargetOutcome {
+                target: target.clone(),
+                save,
+                hits,
+                note,
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dice::source::ScriptedSource;
+
+    #[test]
+    fn test_parse_targets() {
+        let targets = parse_targets("Goblin:+5:center, Orc:3 , Troll:-1:EDGE").unwrap();
+        assert_eq!(targets.len(), 3);
+        assert_eq!((targets[0].save_bonus, targets[0].zone), (5, AreaZone::Center));
+        assert_eq!((targets[1].save_bonus, targets[1].zone), (3, AreaZone::Inside));
+        assert_eq!((targets[2].save_bonus, targets[2].zone), (-1, AreaZone::Edge));
+        assert!(parse_targets("Goblin").is_err());
+        assert!(parse_targets("Goblin:+5:behind").is_err());
+        assert!(parse_targets(" , ").is_err());
+    }
+
+    #[test]
+    fn test_area_damage() {
+        let ball = |zone, resisted| area_damage(SpellShape::Ball, zone, 11, resisted).0;
+        assert_eq!(ball(AreaZone::Center, Some(false)), 15);
+        assert_eq!(ball(AreaZone::Center, Some(true)), 11);
+        assert_eq!(ball(AreaZone::Edge, Some(false)), 11);
+        assert_eq!(ball(AreaZone::Edge, Some(true)), 5);
+        assert_eq!(ball(AreaZone::Inside, None), 11);
+        assert_eq!(area_damage(SpellShape::Line, AreaZone::Inside, 11, Some(true)).0, 0);
+        assert_eq!(area_damage(SpellShape::Line, AreaZone::Inside, 11, Some(false)).0, 11);
+    }
+
+    #[test]
+    fn test_bolt_range_and_cone_width() {
+        assert_eq!(bolt_range_modifier(100, 40), Some(4));
+        assert_eq!(bolt_range_modifier(100, 100), Some(0));
+        assert_eq!(bolt_range_modifier(100, 101), None);
+        assert_eq!(cone_width(30), 15.0);
+    }
+
+    #[test]
+    fn test_resolve_targets_rolls_saves() {
+        let targets = parse_targets("Goblin:+5:center, Orc:+3").unwrap();
+        // Goblin's RSR: 6 + 7 + 5 - 3 = 15, failed; Orc is inside and doesn't save
+        let mut dice = ScriptedSource::new(&[6, 7]);
+        let outcomes = resolve_targets(&mut dice, SpellShape::Ball, &targets, 10, 3);
+        assert_eq!(outcomes[0].hits, 14);
+        assert_eq!(outcomes[0].save.as_ref().unwrap().total, 15);
+        assert!(outcomes[1].save.is_none());
+        assert_eq!(outcomes[1].hits, 10);
+        assert_eq!(dice.remaining(), 0);
+    }
+}
@@ -4,17 +4,28 @@
 //! - 2d10 open-ended rolls with explosion on 19-20
 //! - FEAT table resolution for skill checks
 //! - Fumble detection on unmodified 2
+//! - Persistent character sheets
+//! - Per-channel combat encounter tracking
 
+use std::collections::HashMap;
 use std::env;
+use std::sync::Arc;
 
 use serenity::all::{
     Client, Context, EventHandler, GatewayIntents, GuildId, Interaction, Ready,
 };
 use serenity::async_trait;
+use serenity::prelude::RwLock;
 
+mod character;
+mod combat;
 mod commands;
 mod dice;
 
+use character::{CharacterStore, CharacterStoreKey, DEFAULT_STORE_PATH};
+use combat::EncounterStoreKey;
+use commands::{BoonStoreKey, ContestStoreKey};
+
 struct Handler;
 
 #[async_trait]
@@ -52,21 +63,44 @@ impl EventHandler for Handler {
     }
 
     async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
-        if let Interaction::Command(command) = interaction {
-            match command.data.name.as_str() {
+        match interaction {
+            Interaction::Command(command) => match command.data.name.as_str() {
                 "roll" => commands::handle_roll(&ctx, &command).await,
                 "skill" => commands::handle_skill(&ctx, &command).await,
                 "initiative" => commands::handle_initiative(&ctx, &command).await,
                 "attack" => commands::handle_attack(&ctx, &command).await,
                 "save" => commands::handle_save(&ctx, &command).await,
+                "maneuver" => commands::handle_maneuver(&ctx, &command).await,
+                "dm" => commands::handle_dm(&ctx, &command).await,
                 "cast" => commands::handle_cast(&ctx, &command).await,
+                "spellattack" => commands::handle_spellattack(&ctx, &command).await,
+                "resonance" => commands::handle_resonance(&ctx, &command).await,
+                "asf" => commands::handle_asf(&ctx, &command).await,
                 "fumble" => commands::handle_fumble(&ctx, &command).await,
                 "spellfumble" => commands::handle_spellfumble(&ctx, &command).await,
                 "help" => commands::handle_help(&ctx, &command).await,
                 "difficulty" => commands::handle_difficulty(&ctx, &command).await,
                 "opposed" => commands::handle_opposed(&ctx, &command).await,
+                "odds" => commands::handle_odds(&ctx, &command).await,
+                "char" => commands::handle_char(&ctx, &command).await,
+                "combat" => commands::handle_combat(&ctx, &command).await,
+                "condition" => commands::handle_condition(&ctx, &command).await,
+                "upkeep" => commands::handle_upkeep(&ctx, &command).await,
+                "hp" => commands::handle_hp(&ctx, &command).await,
+                "contest" => commands::handle_contest(&ctx, &command).await,
                 _ => {}
-            }
+            },
+            Interaction::Component(component) => match component.data.custom_id.as_str() {
+                commands::REROLL_BUTTON_ID => commands::handle_contest_reroll(&ctx, &component).await,
+                commands::BOON_MENU_ID | commands::BOON_DONE_ID => {
+                    commands::handle_boon_component(&ctx, &component).await
+                }
+                id if commands::PartialCasting::from_custom_id(id).is_some() => {
+                    commands::handle_partial_cast(&ctx, &component).await
+                }
+                _ => {}
+            },
+            _ => {}
         }
     }
 }
@@ -78,10 +112,18 @@ async fn main() {
 
     let token = env::var("DISCORD_TOKEN").expect("DISCORD_TOKEN must be set in environment or .env file");
 
+    let store_path = env::var("CHARACTER_STORE_PATH").unwrap_or_else(|_| DEFAULT_STORE_PATH.to_string());
+    let characters = CharacterStore::load(&store_path)
+        .unwrap_or_else(|e| panic!("Error loading character store '{}': {:?}", store_path, e));
+
     let intents = GatewayIntents::empty();
 
     let mut client = Client::builder(&token, intents)
         .event_handler(Handler)
+        .type_map_insert::<CharacterStoreKey>(Arc::new(RwLock::new(characters)))
+        .type_map_insert::<EncounterStoreKey>(Arc::new(RwLock::new(HashMap::new())))
+        .type_map_insert::<ContestStoreKey>(Arc::new(RwLock::new(Default::default())))
+        .type_map_insert::<BoonStoreKey>(Arc::new(RwLock::new(Default::default())))
         .await
         .expect("Error creating client");
 